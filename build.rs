@@ -0,0 +1,5 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "grpc-api")]
+    tonic_build::compile_protos("proto/inference.proto")?;
+    Ok(())
+}
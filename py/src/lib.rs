@@ -39,6 +39,10 @@ struct PyModelParameters {
     max_iterations: Option<usize>,
     #[pyo3(get, set)]
     regularization: Option<f32>,
+    #[pyo3(get, set)]
+    solver: Option<String>,
+    #[pyo3(get, set)]
+    pca_components: Option<usize>,
 }
 
 #[pymethods]
@@ -49,12 +53,16 @@ impl PyModelParameters {
         learning_rate: Option<f32>,
         max_iterations: Option<usize>,
         regularization: Option<f32>,
+        solver: Option<String>,
+        pca_components: Option<usize>,
     ) -> Self {
         Self {
             with_bias,
             learning_rate,
             max_iterations,
             regularization,
+            solver,
+            pca_components,
         }
     }
 }
@@ -66,6 +74,9 @@ impl From<PyModelParameters> for ModelParameters {
             learning_rate: params.learning_rate,
             max_iterations: params.max_iterations,
             regularization: params.regularization,
+            solver: params.solver,
+            pca_components: params.pca_components,
+            ..Default::default()
         }
     }
 }
@@ -289,18 +300,20 @@ impl PyContinuum {
         Ok(result.into())
     }
     
-    /// Add a training example
+    /// Add a training example, optionally with an importance weight
     fn add_training_example(
         &self,
         model_name: &str,
         features: Vec<f32>,
         target: f32,
         is_validation: Option<bool>,
+        weight: Option<f32>,
     ) -> PyResult<()> {
-        run_sync(self.api.add_training_example(
+        run_sync(self.api.add_training_example_weighted(
             model_name,
             features,
             target,
+            weight.unwrap_or(1.0),
             is_validation.unwrap_or(false),
         ))
     }
@@ -12,9 +12,12 @@ pub mod server;
 pub use traits::features::FeatureVector;
 pub use traits::model::{Model, ModelError};
 pub use models::linears::LinearRegression;
-pub use models::ridge::RidgeRegression;
+pub use models::ridge::{RidgeRegression, RidgeSolver};
+pub use models::pca::PcaTransform;
+pub use models::feature_extraction::{FeatureExtractor, StatisticFlags};
 pub use server::metrics::ModelStats;
 pub use server::model_server::AtomicModel;
+pub use server::batching::BatchingModel;
 pub use server::continuous_learning::ContinuousLearningConfig;
 
 // Re-export API structures for ease of use
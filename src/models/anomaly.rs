@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+
+use crate::traits::features::FeatureVector;
+use crate::traits::model::{read_model, write_model, Model, ModelError, SerializationFormat};
+
+/// Streaming anomaly/threshold detector: tracks a per-dimension EWMA mean and variance
+/// over every feature it's trained on and scores new points by how many standard
+/// deviations they sit from that running estimate.
+///
+/// Registered like any other `Model`, so it rides the same continuous-learning loop as
+/// regression models -- `add_training_example` buffers points and the background cycle
+/// periodically calls `train`, which folds them into the running statistics rather than
+/// fitting from scratch. `z_threshold` is kept alongside the model in
+/// `ModelServer::anomaly_thresholds` since `predict` itself can only return the score,
+/// not the threshold decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnomalyDetector {
+    z_threshold: f32,
+    /// EWMA smoothing factor, derived from the configured window size as `2 / (window + 1)`
+    alpha: f32,
+    mean: Vec<f32>,
+    variance: Vec<f32>,
+    initialized: bool,
+}
+
+impl AnomalyDetector {
+    /// Create a new detector flagging points beyond `z_threshold` standard deviations
+    /// from the running mean, with `window_size` controlling how quickly that running
+    /// mean/variance forgets old points (smaller window = faster adaptation)
+    pub fn new(z_threshold: f32, window_size: usize) -> Self {
+        Self {
+            z_threshold,
+            alpha: 2.0 / (window_size.max(1) as f32 + 1.0),
+            mean: Vec::new(),
+            variance: Vec::new(),
+            initialized: false,
+        }
+    }
+
+    /// The configured z-score threshold above which a point counts as anomalous
+    pub fn z_threshold(&self) -> f32 {
+        self.z_threshold
+    }
+
+    /// Fold one more observation into the running per-dimension mean/variance via EWMA
+    fn observe(&mut self, feature: &FeatureVector) {
+        let values = feature.as_array();
+
+        if !self.initialized {
+            self.mean = values.iter().copied().collect();
+            self.variance = vec![0.0; values.len()];
+            self.initialized = true;
+            return;
+        }
+
+        for ((mean, variance), &value) in self.mean.iter_mut().zip(self.variance.iter_mut()).zip(values.iter()) {
+            let diff = value - *mean;
+            *mean += self.alpha * diff;
+            *variance = (1.0 - self.alpha) * (*variance + self.alpha * diff * diff);
+        }
+    }
+
+    /// Root-mean-square z-score of `feature` against the running per-dimension
+    /// mean/variance, i.e. how many standard deviations away it sits on average
+    fn score(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
+        if !self.initialized {
+            return Err(ModelError::PredictionError("Anomaly detector has not observed any training data yet".to_string()));
+        }
+
+        let values = feature.as_array();
+        if values.len() != self.mean.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: self.mean.len(),
+                actual: values.len(),
+                context: "anomaly detector feature dimension".to_string(),
+            });
+        }
+
+        let sum_sq: f32 = self
+            .mean
+            .iter()
+            .zip(self.variance.iter())
+            .zip(values.iter())
+            .map(|((mean, variance), &value)| {
+                let std_dev = variance.sqrt();
+                // With no observed spread, any deviation at all is infinitely many std devs away
+                if std_dev <= 1e-12 {
+                    if (value - mean).abs() <= 1e-6 { 0.0 } else { f32::INFINITY }
+                } else {
+                    ((value - mean) / std_dev).powi(2)
+                }
+            })
+            .sum();
+
+        Ok((sum_sq / self.mean.len() as f32).sqrt())
+    }
+}
+
+impl Model for AnomalyDetector {
+    fn train(&mut self, features: &[FeatureVector], _targets: &[f32]) -> Result<(), ModelError> {
+        if features.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
+        }
+
+        for feature in features {
+            self.observe(feature);
+        }
+        Ok(())
+    }
+
+    fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
+        self.score(feature)
+    }
+
+    fn export_parameters(&self) -> Result<Vec<f32>, ModelError> {
+        let mut parameters = self.mean.clone();
+        parameters.extend(self.variance.iter().copied());
+        Ok(parameters)
+    }
+
+    fn import_parameters(&mut self, parameters: Vec<f32>) -> Result<(), ModelError> {
+        if parameters.len() % 2 != 0 {
+            return Err(ModelError::InvalidParameter(
+                "anomaly detector parameters must be an even-length [mean..., variance...] vector".to_string(),
+            ));
+        }
+
+        let midpoint = parameters.len() / 2;
+        self.mean = parameters[..midpoint].to_vec();
+        self.variance = parameters[midpoint..].to_vec();
+        self.initialized = !self.mean.is_empty();
+        Ok(())
+    }
+
+    fn validate(&self, features: &[FeatureVector], _targets: &[f32]) -> Result<f32, ModelError> {
+        if features.is_empty() {
+            return Err(ModelError::ValidationError("Empty validation data".to_string()));
+        }
+
+        let mut total = 0.0f32;
+        for feature in features {
+            total += self.score(feature)?;
+        }
+        Ok(total / features.len() as f32)
+    }
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        write_model(self, path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        *self = read_model(path, format)?;
+        Ok(())
+    }
+
+    fn clone_model(&self) -> Box<dyn Model> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steady_points(value: f32, n: usize) -> Vec<FeatureVector> {
+        (0..n).map(|_| FeatureVector::new(vec![value])).collect()
+    }
+
+    #[test]
+    fn test_anomaly_detector_flags_point_far_from_running_mean() {
+        let mut detector = AnomalyDetector::new(3.0, 20);
+        detector.train(&steady_points(10.0, 50), &vec![0.0; 50]).unwrap();
+
+        let score = detector.predict(&FeatureVector::new(vec![10.0])).unwrap();
+        assert!(score < detector.z_threshold(), "a point matching the running mean shouldn't be anomalous, got score {}", score);
+
+        let score = detector.predict(&FeatureVector::new(vec![1000.0])).unwrap();
+        assert!(score > detector.z_threshold(), "a wildly out-of-range point should be anomalous, got score {}", score);
+    }
+
+    #[test]
+    fn test_anomaly_detector_rejects_prediction_before_training() {
+        let detector = AnomalyDetector::new(3.0, 20);
+        assert!(detector.predict(&FeatureVector::new(vec![1.0])).is_err());
+    }
+
+    #[test]
+    fn test_anomaly_detector_rejects_empty_training_batch() {
+        let mut detector = AnomalyDetector::new(3.0, 20);
+        assert!(detector.train(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_anomaly_detector_rejects_dimension_mismatch() {
+        let mut detector = AnomalyDetector::new(3.0, 20);
+        detector.train(&steady_points(1.0, 10), &vec![0.0; 10]).unwrap();
+
+        let result = detector.predict(&FeatureVector::new(vec![1.0, 2.0]));
+        assert!(matches!(result, Err(ModelError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_anomaly_detector_save_and_load_round_trip() {
+        let mut detector = AnomalyDetector::new(3.0, 20);
+        detector.train(&steady_points(5.0, 30), &vec![0.0; 30]).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_anomaly_detector.json");
+        detector.save_as(path.to_str().unwrap(), SerializationFormat::Json).unwrap();
+
+        let mut loaded = AnomalyDetector::new(3.0, 20);
+        loaded.load_from(path.to_str().unwrap(), SerializationFormat::Json).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let query = FeatureVector::new(vec![50.0]);
+        assert_eq!(loaded.predict(&query).unwrap(), detector.predict(&query).unwrap());
+    }
+}
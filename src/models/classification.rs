@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+
+use crate::traits::features::FeatureVector;
+use crate::traits::model::{read_model, write_model, Classifier, ModelError, SerializationFormat};
+
+/// A single linear decision unit: `weights . x + bias`, trained one-vs-rest against
+/// "every other class" so `LogisticRegression`/`LinearSvm` can reuse the same unit for
+/// an arbitrary number of classes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LinearUnit {
+    weights: Vec<f32>,
+    bias: f32,
+}
+
+impl LinearUnit {
+    fn new(n_features: usize) -> Self {
+        Self { weights: vec![0.0; n_features], bias: 0.0 }
+    }
+
+    fn decision(&self, feature: &FeatureVector) -> f32 {
+        let feature_array = feature.as_array();
+        let dot: f32 = self.weights.iter().zip(feature_array.iter()).map(|(w, x)| w * x).sum();
+        dot + self.bias
+    }
+}
+
+fn sigmoid(z: f32) -> f32 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+/// Validate that `features`/`labels` are non-empty, equal length, and every label is a
+/// valid index into `classes`, shared by `LogisticRegression::train` and `LinearSvm::train`
+fn validate_training_data(features: &[FeatureVector], labels: &[usize], classes: &[String]) -> Result<(), ModelError> {
+    if features.is_empty() || labels.is_empty() {
+        return Err(ModelError::TrainingError("Empty training data".to_string()));
+    }
+
+    if features.len() != labels.len() {
+        return Err(ModelError::DimensionMismatch {
+            expected: features.len(),
+            actual: labels.len(),
+            context: "features vs labels length".to_string(),
+        });
+    }
+
+    if let Some(&label) = labels.iter().find(|&&label| label >= classes.len()) {
+        return Err(ModelError::InvalidParameter(format!(
+            "label {} is out of range for {} classes", label, classes.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Multinomial logistic regression, trained as one binary logistic unit per class
+/// (one-vs-rest) via batch gradient descent on the L2-regularized cross-entropy loss
+///
+/// `predict_scores` returns each unit's sigmoid output renormalized to sum to 1, so the
+/// scores read as per-class probabilities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogisticRegression {
+    classes: Vec<String>,
+    units: Vec<LinearUnit>,
+    learning_rate: f32,
+    max_iterations: usize,
+    l2: f32,
+}
+
+impl LogisticRegression {
+    /// Create a new logistic regression classifier over `classes`, unfitted until `train` is called
+    pub fn new(classes: Vec<String>, learning_rate: f32, max_iterations: usize, l2: f32) -> Self {
+        Self { classes, units: Vec::new(), learning_rate, max_iterations, l2 }
+    }
+}
+
+impl Classifier for LogisticRegression {
+    fn train(&mut self, features: &[FeatureVector], labels: &[usize]) -> Result<(), ModelError> {
+        validate_training_data(features, labels, &self.classes)?;
+
+        let n_features = features[0].dimension();
+        let n_samples = features.len() as f32;
+        let mut units: Vec<LinearUnit> = (0..self.classes.len()).map(|_| LinearUnit::new(n_features)).collect();
+
+        for (class_index, unit) in units.iter_mut().enumerate() {
+            for _ in 0..self.max_iterations {
+                let mut weight_gradient = vec![0.0f32; n_features];
+                let mut bias_gradient = 0.0f32;
+
+                for (feature, &label) in features.iter().zip(labels.iter()) {
+                    let target = if label == class_index { 1.0 } else { 0.0 };
+                    let error = sigmoid(unit.decision(feature)) - target;
+
+                    for (gradient, &value) in weight_gradient.iter_mut().zip(feature.as_array().iter()) {
+                        *gradient += error * value;
+                    }
+                    bias_gradient += error;
+                }
+
+                for (weight, gradient) in unit.weights.iter_mut().zip(weight_gradient.iter()) {
+                    *weight -= self.learning_rate * (gradient / n_samples + self.l2 * *weight);
+                }
+                unit.bias -= self.learning_rate * (bias_gradient / n_samples);
+            }
+        }
+
+        self.units = units;
+        Ok(())
+    }
+
+    fn predict_scores(&self, feature: &FeatureVector) -> Result<Vec<f32>, ModelError> {
+        if self.units.is_empty() {
+            return Err(ModelError::PredictionError("Model not trained".to_string()));
+        }
+
+        let raw: Vec<f32> = self.units.iter().map(|unit| sigmoid(unit.decision(feature))).collect();
+        let total: f32 = raw.iter().sum();
+
+        if total <= 1e-12 {
+            return Ok(vec![1.0 / self.units.len() as f32; self.units.len()]);
+        }
+        Ok(raw.iter().map(|score| score / total).collect())
+    }
+
+    fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        write_model(self, path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        *self = read_model(path, format)?;
+        Ok(())
+    }
+
+    fn clone_classifier(&self) -> Box<dyn Classifier> {
+        Box::new(self.clone())
+    }
+}
+
+/// Linear support vector classifier, trained as one binary unit per class (one-vs-rest)
+/// via subgradient descent on the L2-regularized hinge loss
+///
+/// `predict_scores` returns each unit's raw signed distance from its separating
+/// hyperplane, not a probability -- callers that need a normalized distribution should
+/// use `LogisticRegression` instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearSvm {
+    classes: Vec<String>,
+    units: Vec<LinearUnit>,
+    learning_rate: f32,
+    max_iterations: usize,
+    l2: f32,
+}
+
+impl LinearSvm {
+    /// Create a new linear SVM classifier over `classes`, unfitted until `train` is called
+    pub fn new(classes: Vec<String>, learning_rate: f32, max_iterations: usize, l2: f32) -> Self {
+        Self { classes, units: Vec::new(), learning_rate, max_iterations, l2 }
+    }
+}
+
+impl Classifier for LinearSvm {
+    fn train(&mut self, features: &[FeatureVector], labels: &[usize]) -> Result<(), ModelError> {
+        validate_training_data(features, labels, &self.classes)?;
+
+        let n_features = features[0].dimension();
+        let n_samples = features.len() as f32;
+        let mut units: Vec<LinearUnit> = (0..self.classes.len()).map(|_| LinearUnit::new(n_features)).collect();
+
+        for (class_index, unit) in units.iter_mut().enumerate() {
+            for _ in 0..self.max_iterations {
+                let mut weight_gradient = vec![0.0f32; n_features];
+                let mut bias_gradient = 0.0f32;
+
+                for (feature, &label) in features.iter().zip(labels.iter()) {
+                    let target = if label == class_index { 1.0 } else { -1.0 };
+
+                    // Hinge loss's subgradient is zero once a sample clears its margin
+                    if target * unit.decision(feature) < 1.0 {
+                        for (gradient, &value) in weight_gradient.iter_mut().zip(feature.as_array().iter()) {
+                            *gradient -= target * value;
+                        }
+                        bias_gradient -= target;
+                    }
+                }
+
+                for (weight, gradient) in unit.weights.iter_mut().zip(weight_gradient.iter()) {
+                    *weight -= self.learning_rate * (gradient / n_samples + self.l2 * *weight);
+                }
+                unit.bias -= self.learning_rate * (bias_gradient / n_samples);
+            }
+        }
+
+        self.units = units;
+        Ok(())
+    }
+
+    fn predict_scores(&self, feature: &FeatureVector) -> Result<Vec<f32>, ModelError> {
+        if self.units.is_empty() {
+            return Err(ModelError::PredictionError("Model not trained".to_string()));
+        }
+
+        Ok(self.units.iter().map(|unit| unit.decision(feature)).collect())
+    }
+
+    fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        write_model(self, path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        *self = read_model(path, format)?;
+        Ok(())
+    }
+
+    fn clone_classifier(&self) -> Box<dyn Classifier> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_class_linearly_separable() -> (Vec<FeatureVector>, Vec<usize>) {
+        let features = vec![
+            FeatureVector::new(vec![-2.0]),
+            FeatureVector::new(vec![-1.0]),
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+        ];
+        let labels = vec![0, 0, 1, 1];
+        (features, labels)
+    }
+
+    #[test]
+    fn test_logistic_regression_separates_linearly_separable_classes() {
+        let (features, labels) = two_class_linearly_separable();
+        let mut model = LogisticRegression::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 2000, 0.0);
+        model.train(&features, &labels).unwrap();
+
+        let scores = model.predict_scores(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!(scores[1] > scores[0], "a strongly positive input should score higher for the positive class");
+    }
+
+    #[test]
+    fn test_logistic_regression_scores_sum_to_one() {
+        let (features, labels) = two_class_linearly_separable();
+        let mut model = LogisticRegression::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 500, 0.0);
+        model.train(&features, &labels).unwrap();
+
+        let scores = model.predict_scores(&FeatureVector::new(vec![0.5])).unwrap();
+        let total: f32 = scores.iter().sum();
+        assert!((total - 1.0).abs() < 1e-4, "scores should read as a probability distribution, got {:?}", scores);
+    }
+
+    #[test]
+    fn test_logistic_regression_rejects_label_out_of_range() {
+        let (features, _) = two_class_linearly_separable();
+        let mut model = LogisticRegression::new(vec!["only_class".to_string()], 0.1, 10, 0.0);
+        let result = model.train(&features, &[0, 0, 1, 0]);
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_logistic_regression_rejects_mismatched_lengths() {
+        let (features, _) = two_class_linearly_separable();
+        let mut model = LogisticRegression::new(vec!["a".to_string(), "b".to_string()], 0.1, 10, 0.0);
+        let result = model.train(&features, &[0, 1]);
+        assert!(matches!(result, Err(ModelError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_logistic_regression_rejects_prediction_before_training() {
+        let model = LogisticRegression::new(vec!["a".to_string(), "b".to_string()], 0.1, 10, 0.0);
+        assert!(model.predict_scores(&FeatureVector::new(vec![1.0])).is_err());
+    }
+
+    #[test]
+    fn test_logistic_regression_save_and_load_round_trip() {
+        let (features, labels) = two_class_linearly_separable();
+        let mut model = LogisticRegression::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 500, 0.0);
+        model.train(&features, &labels).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_logistic_regression.json");
+        model.save_as(path.to_str().unwrap(), SerializationFormat::Json).unwrap();
+
+        let mut loaded = LogisticRegression::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 500, 0.0);
+        loaded.load_from(path.to_str().unwrap(), SerializationFormat::Json).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let query = FeatureVector::new(vec![3.0]);
+        assert_eq!(loaded.predict_scores(&query).unwrap(), model.predict_scores(&query).unwrap());
+    }
+
+    #[test]
+    fn test_linear_svm_separates_linearly_separable_classes() {
+        let (features, labels) = two_class_linearly_separable();
+        let mut model = LinearSvm::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 2000, 0.01);
+        model.train(&features, &labels).unwrap();
+
+        let scores = model.predict_scores(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!(scores[1] > scores[0], "a strongly positive input should have a higher positive-class decision value");
+    }
+
+    #[test]
+    fn test_linear_svm_decision_values_are_not_normalized() {
+        let (features, labels) = two_class_linearly_separable();
+        let mut model = LinearSvm::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 2000, 0.01);
+        model.train(&features, &labels).unwrap();
+
+        let scores = model.predict_scores(&FeatureVector::new(vec![5.0])).unwrap();
+        let total: f32 = scores.iter().sum();
+        assert!((total - 1.0).abs() > 1e-3, "SVM decision values shouldn't happen to sum to 1 like a probability distribution");
+    }
+
+    #[test]
+    fn test_linear_svm_rejects_prediction_before_training() {
+        let model = LinearSvm::new(vec!["a".to_string(), "b".to_string()], 0.1, 10, 0.01);
+        assert!(model.predict_scores(&FeatureVector::new(vec![1.0])).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use crate::traits::features::FeatureVector;
+use crate::traits::model::ModelError;
+
+/// Which summary statistics `FeatureExtractor` includes ahead of the spectral bins.
+/// Statistics are always emitted in mean, std-dev, min, max, slope order, skipping
+/// whichever are disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatisticFlags {
+    /// Arithmetic mean of the window
+    pub mean: bool,
+    /// Population standard deviation of the window
+    pub std_dev: bool,
+    /// Minimum value in the window
+    pub min: bool,
+    /// Maximum value in the window
+    pub max: bool,
+    /// First/last-difference slope: `(window[last] - window[0]) / (len - 1)`
+    pub slope: bool,
+}
+
+impl StatisticFlags {
+    /// All five summary statistics enabled
+    pub fn all() -> Self {
+        Self {
+            mean: true,
+            std_dev: true,
+            min: true,
+            max: true,
+            slope: true,
+        }
+    }
+
+    /// No summary statistics enabled; the extracted vector is spectral bins only
+    pub fn none() -> Self {
+        Self {
+            mean: false,
+            std_dev: false,
+            min: false,
+            max: false,
+            slope: false,
+        }
+    }
+
+    fn count(&self) -> usize {
+        [self.mean, self.std_dev, self.min, self.max, self.slope]
+            .iter()
+            .filter(|&&enabled| enabled)
+            .count()
+    }
+}
+
+impl Default for StatisticFlags {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Turns a raw time-series window into a `FeatureVector` by concatenating summary
+/// statistics (mean, std, min, max, first/last-difference slope) with the magnitudes
+/// of the first `fft_bins` low-frequency FFT coefficients of the window (zero-padded
+/// to the next power of two). `dimension()` reports the resulting feature width so a
+/// model fed these vectors always sees a stable layout.
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureExtractor {
+    /// Which summary statistics to include, in a fixed mean/std/min/max/slope order
+    statistics: StatisticFlags,
+    /// Number of low-frequency FFT magnitude bins appended after the statistics
+    fft_bins: usize,
+}
+
+impl FeatureExtractor {
+    /// Create an extractor with all summary statistics enabled and `fft_bins` spectral bins
+    pub fn new(fft_bins: usize) -> Self {
+        Self {
+            statistics: StatisticFlags::all(),
+            fft_bins,
+        }
+    }
+
+    /// Select which summary statistics are included ahead of the spectral bins
+    pub fn with_statistics(mut self, statistics: StatisticFlags) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Resulting feature width: enabled statistics plus `fft_bins` spectral magnitudes
+    pub fn dimension(&self) -> usize {
+        self.statistics.count() + self.fft_bins
+    }
+
+    /// Extract a `FeatureVector` from a raw time-series window
+    pub fn extract(&self, window: &[f32]) -> Result<FeatureVector, ModelError> {
+        if window.is_empty() {
+            return Err(ModelError::ValidationError(
+                "Cannot extract features from an empty window".to_string(),
+            ));
+        }
+
+        let mut values = Vec::with_capacity(self.dimension());
+
+        let mean = window.iter().sum::<f32>() / window.len() as f32;
+        if self.statistics.mean {
+            values.push(mean);
+        }
+        if self.statistics.std_dev {
+            let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / window.len() as f32;
+            values.push(variance.sqrt());
+        }
+        if self.statistics.min {
+            values.push(window.iter().copied().fold(f32::INFINITY, f32::min));
+        }
+        if self.statistics.max {
+            values.push(window.iter().copied().fold(f32::NEG_INFINITY, f32::max));
+        }
+        if self.statistics.slope {
+            let span = (window.len() - 1).max(1) as f32;
+            values.push((window[window.len() - 1] - window[0]) / span);
+        }
+
+        values.extend(spectral_magnitudes(window, self.fft_bins));
+
+        Ok(FeatureVector::new(values))
+    }
+}
+
+/// Magnitude `(re^2 + im^2).sqrt()` of the first `num_bins` low-frequency FFT
+/// coefficients of `window`, zero-padded up to the next power of two. Bins beyond the
+/// padded spectrum's length (possible for a very short window and a large
+/// `num_bins`) are filled with zero.
+fn spectral_magnitudes(window: &[f32], num_bins: usize) -> Vec<f32> {
+    let padded_len = window.len().next_power_of_two().max(1);
+    let mut real = vec![0.0f32; padded_len];
+    real[..window.len()].copy_from_slice(window);
+    let mut imag = vec![0.0f32; padded_len];
+
+    fft_in_place(&mut real, &mut imag);
+
+    (0..num_bins)
+        .map(|i| {
+            if i < padded_len {
+                (real[i] * real[i] + imag[i] * imag[i]).sqrt()
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT; `re`/`im` must have a power-of-two length
+fn fft_in_place(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * std::f32::consts::PI / len as f32;
+        let (w_re, w_im) = (angle.cos(), angle.sin());
+
+        let mut start = 0;
+        while start < n {
+            let (mut cur_re, mut cur_im) = (1.0f32, 0.0f32);
+            for k in 0..len / 2 {
+                let (u_re, u_im) = (re[start + k], im[start + k]);
+                let (v_re, v_im) = (
+                    re[start + k + len / 2] * cur_re - im[start + k + len / 2] * cur_im,
+                    re[start + k + len / 2] * cur_im + im[start + k + len / 2] * cur_re,
+                );
+
+                re[start + k] = u_re + v_re;
+                im[start + k] = u_im + v_im;
+                re[start + k + len / 2] = u_re - v_re;
+                im[start + k + len / 2] = u_im - v_im;
+
+                let next_re = cur_re * w_re - cur_im * w_im;
+                let next_im = cur_re * w_im + cur_im * w_re;
+                cur_re = next_re;
+                cur_im = next_im;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimension_matches_statistics_and_fft_bins() {
+        let extractor = FeatureExtractor::new(4);
+        assert_eq!(extractor.dimension(), 5 + 4);
+
+        let extractor = FeatureExtractor::new(4).with_statistics(StatisticFlags::none());
+        assert_eq!(extractor.dimension(), 4);
+    }
+
+    #[test]
+    fn test_extract_matches_dimension() {
+        let extractor = FeatureExtractor::new(3);
+        let window = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let features = extractor.extract(&window).unwrap();
+        assert_eq!(features.dimension(), extractor.dimension());
+    }
+
+    #[test]
+    fn test_extract_summary_statistics() {
+        let extractor = FeatureExtractor::new(0);
+        let window = vec![1.0, 2.0, 3.0, 4.0];
+        let features = extractor.extract(&window).unwrap();
+        let values = features.as_array();
+
+        assert!((values[0] - 2.5).abs() < 1e-6, "mean");
+        assert!((values[1] - 1.1180340).abs() < 1e-4, "std_dev"); // population std of 1..4
+        assert_eq!(values[2], 1.0, "min");
+        assert_eq!(values[3], 4.0, "max");
+        assert!((values[4] - 1.0).abs() < 1e-6, "slope"); // (4 - 1) / 3
+    }
+
+    #[test]
+    fn test_extract_only_selected_statistics() {
+        let extractor = FeatureExtractor::new(0).with_statistics(StatisticFlags {
+            mean: true,
+            std_dev: false,
+            min: false,
+            max: false,
+            slope: false,
+        });
+        let window = vec![1.0, 2.0, 3.0, 4.0];
+        let features = extractor.extract(&window).unwrap();
+        assert_eq!(features.dimension(), 1);
+        assert!((features.as_array()[0] - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_extract_rejects_empty_window() {
+        let extractor = FeatureExtractor::new(2);
+        assert!(extractor.extract(&[]).is_err());
+    }
+
+    #[test]
+    fn test_spectral_magnitudes_picks_out_dominant_frequency() {
+        // 8-sample window, one full cycle per 4 samples -> energy concentrated in bin 2
+        let window: Vec<f32> = (0..8)
+            .map(|i| (2.0 * std::f32::consts::PI * i as f32 / 4.0).sin())
+            .collect();
+
+        let magnitudes = spectral_magnitudes(&window, 4);
+        assert_eq!(magnitudes.len(), 4);
+
+        let peak_bin = magnitudes
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(idx, _)| idx)
+            .unwrap();
+        assert_eq!(peak_bin, 2, "energy should concentrate in the bin matching the signal's frequency");
+    }
+
+    #[test]
+    fn test_spectral_magnitudes_pads_short_windows_to_power_of_two() {
+        let window = vec![1.0, 1.0, 1.0];
+        let magnitudes = spectral_magnitudes(&window, 2);
+        assert_eq!(magnitudes.len(), 2);
+        // DC bin should reflect the (zero-padded) sum of the window
+        assert!((magnitudes[0] - 3.0).abs() < 1e-4);
+    }
+}
@@ -1,12 +1,65 @@
 use ndarray::{Array1, Array2};
-use ndarray_linalg::Solve;
+use ndarray_linalg::{Inverse, Solve};
+use rand::Rng;
 use serde::{Serialize, Deserialize};
-use std::fs::File;
-use std::io::{BufReader, BufWriter};
-use serde_json;
 
 use crate::traits::features::FeatureVector;
-use crate::traits::model::{Model, ModelError};
+use crate::traits::model::{read_model, write_model, IncrementalModel, Metrics, Model, ModelError, ModelFactory, SerializationFormat, UncertaintyModel};
+
+/// Penalty applied to the least-squares objective, mirroring liblinear's solver-type choice
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Regularization {
+    /// Unpenalized ordinary least squares
+    None,
+    /// L2 penalty: solves `(X^T X + λI) w = X^T y`, which also fixes a singular `X^T X`
+    Ridge(f32),
+    /// L1 penalty, fit via cyclic coordinate descent
+    Lasso(f32),
+    /// Combined L1/L2 penalty, fit via cyclic coordinate descent
+    ElasticNet { l1: f32, l2: f32 },
+}
+
+/// Max weight change below which coordinate descent is considered converged
+const COORDINATE_DESCENT_TOLERANCE: f32 = 1e-4;
+
+/// `sign(a) * max(|a| - gamma, 0)`, the proximal operator for the L1 penalty
+fn soft_threshold(a: f32, gamma: f32) -> f32 {
+    if a > gamma {
+        a - gamma
+    } else if a < -gamma {
+        a + gamma
+    } else {
+        0.0
+    }
+}
+
+/// Early-stopping configuration for `LinearRegression::train_with_early_stopping`,
+/// modeled on tangram's early-stopping monitor
+#[derive(Debug, Clone, Copy)]
+pub struct EarlyStopping {
+    /// Consecutive non-improving iterations to tolerate before stopping
+    pub patience: usize,
+    /// Minimum decrease in validation MSE that counts as an improvement
+    pub min_delta: f32,
+    /// Fraction of the training data held out for validation, assigned per-sample
+    pub val_fraction: f32,
+}
+
+/// Validation-split metrics report returned by `LinearRegression::train_with_early_stopping`,
+/// in the spirit of burn's training summary
+#[derive(Debug, Clone, Copy)]
+pub struct TrainingSummary {
+    /// Mean squared error on the validation split, using the restored best weights
+    pub mse: f32,
+    /// Root mean squared error on the validation split
+    pub rmse: f32,
+    /// Mean absolute error on the validation split
+    pub mae: f32,
+    /// R² (coefficient of determination) on the validation split
+    pub r_squared: f32,
+    /// Iteration at which training stopped (may be less than `max_iterations`)
+    pub stopped_at: usize,
+}
 
 /// Linear regression model
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +74,20 @@ pub struct LinearRegression {
     max_iterations: usize,
     /// Whether the model has been trained
     trained: bool,
+    /// Penalty applied to the least-squares objective
+    regularization: Regularization,
+    /// Cached inverse Gram matrix `(X^T X)^{-1}` (ridge-regularized form when
+    /// `Regularization::Ridge` was used) from the last OLS fit, row-major; lets
+    /// `predict_with_variance` compute `x̃ᵀ(X^T X)^{-1}x̃` without re-solving
+    #[serde(default)]
+    inverse_gram: Option<Vec<Vec<f32>>>,
+    /// Cached residual variance estimate `σ² = SSE / (n − p)` from the last OLS fit
+    #[serde(default)]
+    residual_variance: Option<f32>,
+    /// Per-feature mean over the training set, captured during `train`; the baseline
+    /// that `explain`'s Shapley-value attributions are measured against
+    #[serde(default)]
+    feature_means: Vec<f32>,
 }
 
 impl LinearRegression {
@@ -32,9 +99,19 @@ impl LinearRegression {
             learning_rate,
             max_iterations,
             trained: false,
+            regularization: Regularization::None,
+            inverse_gram: None,
+            residual_variance: None,
+            feature_means: Vec::new(),
         }
     }
-    
+
+    /// Fit with the given regularization instead of plain OLS
+    pub fn with_regularization(mut self, regularization: Regularization) -> Self {
+        self.regularization = regularization;
+        self
+    }
+
     /// Create design matrix from feature vectors
     fn create_design_matrix(&self, features: &[FeatureVector]) -> Array2<f32> {
         let n_samples = features.len();
@@ -67,17 +144,27 @@ impl LinearRegression {
         design_matrix
     }
     
-    /// Train using ordinary least squares
+    /// Train using ordinary least squares, optionally ridge-penalized
     fn fit_ols(&mut self, x: Array2<f32>, y: Array1<f32>) -> Result<(), ModelError> {
         // Calculate X^T * X
-        let xt_x = x.t().dot(&x);
-        
+        let mut xt_x = x.t().dot(&x);
+
         // Calculate X^T * y
         let xt_y = x.t().dot(&y);
-        
-        // Solve (X^T * X) * w = X^T * y
+
+        // Add the ridge penalty to the diagonal, skipping the bias column so the
+        // intercept stays unpenalized
+        if let Regularization::Ridge(lambda) = self.regularization {
+            let start = if self.with_bias { 1 } else { 0 };
+            for j in start..xt_x.ncols() {
+                xt_x[[j, j]] += lambda;
+            }
+        }
+
+        // Solve (X^T * X [+ λI]) * w = X^T * y
         match xt_x.solve(&xt_y) {
             Ok(weights) => {
+                self.cache_uncertainty_estimates(&xt_x, &x, &y, &weights);
                 self.weights = weights.to_vec();
                 self.trained = true;
                 Ok(())
@@ -85,6 +172,97 @@ impl LinearRegression {
             Err(e) => Err(ModelError::TrainingError(format!("Failed to solve OLS: {}", e))),
         }
     }
+
+    /// Cache `(X^T X)^{-1}` and the residual variance `σ² = SSE / (n − p)` so
+    /// `predict_with_variance` can report calibrated confidence intervals
+    ///
+    /// Non-fatal if the Gram matrix can't be inverted or there are too few samples
+    /// to estimate a residual variance (`n <= p`); uncertainty estimates are simply
+    /// left unavailable in that case, while the point-estimate fit still succeeds.
+    fn cache_uncertainty_estimates(&mut self, xt_x: &Array2<f32>, x: &Array2<f32>, y: &Array1<f32>, weights: &Array1<f32>) {
+        let inverse_gram = match xt_x.inv() {
+            Ok(inv) => inv,
+            Err(_) => {
+                self.inverse_gram = None;
+                self.residual_variance = None;
+                return;
+            }
+        };
+
+        let n = x.nrows();
+        let p = weights.len();
+        if n <= p {
+            self.inverse_gram = None;
+            self.residual_variance = None;
+            return;
+        }
+
+        let residuals = y - &x.dot(weights);
+        let sse: f32 = residuals.iter().map(|r| r * r).sum();
+
+        self.inverse_gram = Some(inverse_gram.outer_iter().map(|row| row.to_vec()).collect());
+        self.residual_variance = Some(sse / (n - p) as f32);
+    }
+
+    /// Train an L1 or elastic-net penalized fit via cyclic coordinate descent
+    ///
+    /// Precomputes each column's squared norm `z_j`, then repeatedly visits every
+    /// coordinate, forms the partial residual against the other coordinates' current
+    /// fit, and updates `w_j = soft_threshold(r_j, l1) / (z_j + l2)`. The bias
+    /// coordinate (if any) is left unpenalized. Stops once the largest weight change
+    /// in a pass falls below `COORDINATE_DESCENT_TOLERANCE` or `max_iterations` is hit.
+    fn fit_coordinate_descent(&mut self, x: Array2<f32>, y: Array1<f32>, l1: f32, l2: f32) -> Result<(), ModelError> {
+        let n_samples = x.nrows();
+        let n_features = x.ncols();
+        let bias_index = if self.with_bias { Some(0usize) } else { None };
+
+        let column_norms: Vec<f32> = (0..n_features)
+            .map(|j| x.column(j).iter().map(|v| v * v).sum())
+            .collect();
+
+        let mut weights = Array1::<f32>::zeros(n_features);
+        let mut predictions = Array1::<f32>::zeros(n_samples);
+
+        for _ in 0..self.max_iterations {
+            let mut max_change: f32 = 0.0;
+
+            for j in 0..n_features {
+                let column = x.column(j);
+                let w_j = weights[j];
+
+                let mut r_j = 0.0f32;
+                for i in 0..n_samples {
+                    r_j += column[i] * (y[i] - predictions[i] + w_j * column[i]);
+                }
+
+                let z_j = column_norms[j];
+                let new_w_j = if Some(j) == bias_index {
+                    if z_j > 1e-12 { r_j / z_j } else { 0.0 }
+                } else if z_j + l2 > 1e-12 {
+                    soft_threshold(r_j, l1) / (z_j + l2)
+                } else {
+                    0.0
+                };
+
+                let delta = new_w_j - w_j;
+                if delta != 0.0 {
+                    for i in 0..n_samples {
+                        predictions[i] += delta * column[i];
+                    }
+                }
+                weights[j] = new_w_j;
+                max_change = max_change.max(delta.abs());
+            }
+
+            if max_change < COORDINATE_DESCENT_TOLERANCE {
+                break;
+            }
+        }
+
+        self.weights = weights.to_vec();
+        self.trained = true;
+        Ok(())
+    }
     
     /// Train using gradient descent
     fn fit_gradient_descent(&mut self, x: &Array2<f32>, y: &Array1<f32>) -> Result<(), ModelError> {
@@ -112,6 +290,208 @@ impl LinearRegression {
         self.trained = true;
         Ok(())
     }
+
+    /// Dispatch to coordinate descent, OLS, or gradient descent depending on the
+    /// chosen regularization and problem size for an already-built design matrix/target pair
+    fn dispatch_fit(&mut self, x: Array2<f32>, y: Array1<f32>) -> Result<(), ModelError> {
+        // Only fit_ols caches fresh uncertainty estimates; clear any stale ones up
+        // front so a fit that takes a different path doesn't leave them pointing at
+        // an earlier model.
+        self.inverse_gram = None;
+        self.residual_variance = None;
+
+        match self.regularization {
+            Regularization::Lasso(l1) => self.fit_coordinate_descent(x, y, l1, 0.0),
+            Regularization::ElasticNet { l1, l2 } => self.fit_coordinate_descent(x, y, l1, l2),
+            // Ridge's penalty is what fixes a singular X^T*X, so always route it
+            // through OLS rather than falling back to gradient descent
+            Regularization::Ridge(_) => self.fit_ols(x, y),
+            Regularization::None => {
+                if x.ncols() < 1000 && x.nrows() > x.ncols() {
+                    // Use OLS for smaller problems
+                    self.fit_ols(x, y)
+                } else {
+                    // Use gradient descent for larger problems or when X^T*X is singular
+                    self.fit_gradient_descent(&x, &y)
+                }
+            }
+        }
+    }
+
+    /// Rescale each row of the design matrix and its target by `sqrt(weight)`
+    ///
+    /// Minimizing the unweighted least-squares objective on these rescaled rows is
+    /// equivalent to minimizing the weighted objective `sum(w_i * (y_i - x_i*w)^2)` on the
+    /// originals, so both OLS and gradient descent can be reused unchanged for weighted fitting.
+    fn apply_sample_weights(x: &Array2<f32>, y: &Array1<f32>, sample_weights: &[f32]) -> (Array2<f32>, Array1<f32>) {
+        let mut x_weighted = x.clone();
+        let mut y_weighted = y.clone();
+        for (i, &w) in sample_weights.iter().enumerate() {
+            let sw = w.max(0.0).sqrt();
+            x_weighted.row_mut(i).mapv_inplace(|v| v * sw);
+            y_weighted[i] *= sw;
+        }
+        (x_weighted, y_weighted)
+    }
+
+    /// Per-feature mean over `features` (excluding any bias column)
+    fn compute_feature_means(features: &[FeatureVector]) -> Vec<f32> {
+        let n_samples = features.len();
+        let n_features = if features.is_empty() { 0 } else { features[0].dimension() };
+
+        let mut sums = vec![0.0f32; n_features];
+        for feature in features {
+            let feature_array = feature.as_array();
+            for j in 0..n_features {
+                sums[j] += feature_array[j];
+            }
+        }
+
+        sums.iter().map(|sum| sum / n_samples as f32).collect()
+    }
+
+    /// Additive Shapley-value decomposition of a single prediction
+    ///
+    /// For a linear model, feature `i`'s exact Shapley value is `w_i * (x_i - mean_i)`,
+    /// where `mean_i` is that feature's mean over the training set; the returned
+    /// contributions plus `predict` evaluated at the training means sum exactly to
+    /// `predict(feature)`.
+    pub fn explain(&self, feature: &FeatureVector) -> Result<Vec<f32>, ModelError> {
+        if !self.trained {
+            return Err(ModelError::PredictionError("Model not trained".to_string()));
+        }
+
+        let expected_dim = if self.with_bias {
+            self.weights.len() - 1
+        } else {
+            self.weights.len()
+        };
+
+        if feature.dimension() != expected_dim {
+            return Err(ModelError::DimensionMismatch {
+                expected: expected_dim,
+                actual: feature.dimension(),
+                context: "Feature dimension doesn't match model weights".to_string(),
+            });
+        }
+
+        if self.feature_means.len() != expected_dim {
+            return Err(ModelError::PredictionError(
+                "Model has no cached training feature means; retrain to enable explanations".to_string(),
+            ));
+        }
+
+        let feature_array = feature.as_array();
+        let offset = if self.with_bias { 1 } else { 0 };
+
+        let contributions = (0..expected_dim)
+            .map(|i| self.weights[i + offset] * (feature_array[i] - self.feature_means[i]))
+            .collect();
+
+        Ok(contributions)
+    }
+
+    /// Train via gradient descent with an internal validation split and early stopping
+    ///
+    /// Holds out `config.val_fraction` of `features`/`targets` for validation
+    /// (assigned per-sample, mirroring `TrainingBuffer::add_auto`), then runs
+    /// gradient descent, tracking validation MSE after every iteration. Stops once
+    /// `config.patience` consecutive iterations fail to improve validation MSE by
+    /// more than `config.min_delta`, restoring the best weights seen rather than
+    /// the final ones, and returns a `TrainingSummary` of the restored model's
+    /// validation-split metrics.
+    pub fn train_with_early_stopping(
+        &mut self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        config: EarlyStopping,
+    ) -> Result<TrainingSummary, ModelError> {
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
+        }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Number of feature vectors doesn't match number of targets".to_string(),
+            });
+        }
+
+        if !(0.0..1.0).contains(&config.val_fraction) {
+            return Err(ModelError::InvalidParameter("val_fraction must be in [0.0, 1.0)".to_string()));
+        }
+
+        let mut train_features = Vec::new();
+        let mut train_targets = Vec::new();
+        let mut val_features = Vec::new();
+        let mut val_targets = Vec::new();
+        for (feature, &target) in features.iter().zip(targets.iter()) {
+            if rand::thread_rng().gen_range(0.0..1.0) < config.val_fraction {
+                val_features.push(feature.clone());
+                val_targets.push(target);
+            } else {
+                train_features.push(feature.clone());
+                train_targets.push(target);
+            }
+        }
+
+        if train_features.is_empty() || val_features.is_empty() {
+            return Err(ModelError::TrainingError(
+                "Validation split left an empty train or validation set; adjust val_fraction or provide more data".to_string(),
+            ));
+        }
+
+        self.feature_means = Self::compute_feature_means(&train_features);
+
+        let x = self.create_design_matrix(&train_features);
+        let y = Array1::from(train_targets);
+        let n_samples = x.nrows() as f32;
+        let mut weights = Array1::<f32>::zeros(x.ncols());
+
+        let mut best_weights = weights.clone();
+        let mut best_val_mse = f32::INFINITY;
+        let mut stalled_iterations = 0usize;
+        let mut stopped_at = 0usize;
+
+        for iteration in 0..self.max_iterations {
+            let predictions = x.dot(&weights);
+            let errors = &y - &predictions;
+            let gradient = x.t().dot(&errors) * (-2.0 / n_samples);
+            weights = &weights - &(self.learning_rate * gradient);
+            stopped_at = iteration + 1;
+
+            self.weights = weights.to_vec();
+            self.trained = true;
+            let val_predictions = self.predict_batch(&val_features)?;
+            let val_mse = self.mse(&val_predictions, &val_targets)?;
+
+            if best_val_mse - val_mse > config.min_delta {
+                best_val_mse = val_mse;
+                best_weights = weights.clone();
+                stalled_iterations = 0;
+            } else {
+                stalled_iterations += 1;
+                if stalled_iterations >= config.patience {
+                    break;
+                }
+            }
+        }
+
+        self.weights = best_weights.to_vec();
+        self.trained = true;
+        self.inverse_gram = None;
+        self.residual_variance = None;
+
+        let val_predictions = self.predict_batch(&val_features)?;
+        Ok(TrainingSummary {
+            mse: self.mse(&val_predictions, &val_targets)?,
+            rmse: self.rmse(&val_predictions, &val_targets)?,
+            mae: self.mae(&val_predictions, &val_targets)?,
+            r_squared: self.r_squared(&val_predictions, &val_targets)?,
+            stopped_at,
+        })
+    }
 }
 
 impl Model for LinearRegression {
@@ -119,7 +499,7 @@ impl Model for LinearRegression {
         if features.is_empty() || targets.is_empty() {
             return Err(ModelError::TrainingError("Empty training data".to_string()));
         }
-        
+
         if features.len() != targets.len() {
             return Err(ModelError::DimensionMismatch {
                 expected: features.len(),
@@ -127,19 +507,52 @@ impl Model for LinearRegression {
                 context: "Number of feature vectors doesn't match number of targets".to_string(),
             });
         }
-        
+
         // Create design matrix
         let x = self.create_design_matrix(features);
         let y = Array1::from(targets.to_vec());
-        
-        // Choose training method based on data size
-        if x.ncols() < 1000 && x.nrows() > x.ncols() {
-            // Use OLS for smaller problems
-            self.fit_ols(x, y)
-        } else {
-            // Use gradient descent for larger problems or when X^T*X is singular
-            self.fit_gradient_descent(&x, &y)
+        self.feature_means = Self::compute_feature_means(features);
+
+        self.dispatch_fit(x, y)
+    }
+
+    fn train_weighted(
+        &mut self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        let sample_weights = match sample_weights {
+            Some(w) => w,
+            None => return self.train(features, targets),
+        };
+
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
+        }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Number of feature vectors doesn't match number of targets".to_string(),
+            });
         }
+
+        if sample_weights.len() != features.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: sample_weights.len(),
+                context: "sample_weights doesn't match number of feature vectors".to_string(),
+            });
+        }
+
+        let x = self.create_design_matrix(features);
+        let y = Array1::from(targets.to_vec());
+        let (x, y) = Self::apply_sample_weights(&x, &y, sample_weights);
+        self.feature_means = Self::compute_feature_means(features);
+
+        self.dispatch_fit(x, y)
     }
     
     fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
@@ -218,35 +631,279 @@ impl Model for LinearRegression {
         let mse = sum_squared_error / predictions.len() as f32;
         Ok(mse)
     }
-    
-    fn save(&self, path: &str) -> Result<(), ModelError> {
-        let file = File::create(path)?;
-        let writer = BufWriter::new(file);
-        
-        match serde_json::to_writer(writer, self) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(ModelError::SerializationError(e.to_string())),
+
+    fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        let sample_weights = match sample_weights {
+            Some(w) => w,
+            None => return self.validate(features, targets),
+        };
+
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::ValidationError("Empty validation data".to_string()));
         }
-    }
-    
-    fn load(&mut self, path: &str) -> Result<(), ModelError> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        
-        match serde_json::from_reader(reader) {
-            Ok(model) => {
-                *self = model;
-                Ok(())
-            }
-            Err(e) => Err(ModelError::SerializationError(e.to_string())),
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Validation features vs targets".to_string(),
+            });
         }
+
+        if sample_weights.len() != features.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: sample_weights.len(),
+                context: "sample_weights doesn't match number of feature vectors".to_string(),
+            });
+        }
+
+        let predictions = self.predict_batch(features)?;
+
+        let mut weighted_sum_squared_error = 0.0f32;
+        let mut weight_total = 0.0f32;
+        for i in 0..predictions.len() {
+            let error = predictions[i] - targets[i];
+            weighted_sum_squared_error += sample_weights[i] * error * error;
+            weight_total += sample_weights[i];
+        }
+
+        if weight_total.abs() < 1e-12 {
+            return Err(ModelError::ValidationError("Sum of sample weights is zero".to_string()));
+        }
+
+        Ok(weighted_sum_squared_error / weight_total)
     }
-    
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        write_model(self, path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        *self = read_model(path, format)?;
+        Ok(())
+    }
+
     fn clone_model(&self) -> Box<dyn Model> {
         Box::new(self.clone())
     }
 }
 
+impl UncertaintyModel for LinearRegression {
+    /// Predict the mean and variance for `feature`
+    ///
+    /// Requires a cached `(X^T X)^{-1}` and residual variance from a prior OLS fit
+    /// (plain or ridge-regularized); unavailable for models fit via gradient descent
+    /// or coordinate descent, since those don't solve a Gram matrix to invert.
+    fn predict_with_variance(&self, feature: &FeatureVector) -> Result<(f32, f32), ModelError> {
+        let mean = self.predict(feature)?;
+
+        let inverse_gram = self.inverse_gram.as_ref().ok_or_else(|| {
+            ModelError::PredictionError(
+                "No cached inverse Gram matrix; uncertainty estimates require an OLS or ridge fit".to_string(),
+            )
+        })?;
+        let sigma_squared = self.residual_variance.ok_or_else(|| {
+            ModelError::PredictionError(
+                "No cached residual variance; uncertainty estimates require an OLS or ridge fit".to_string(),
+            )
+        })?;
+
+        let mut row = Vec::with_capacity(inverse_gram.len());
+        if self.with_bias {
+            row.push(1.0);
+        }
+        row.extend(feature.as_array().iter().copied());
+
+        if row.len() != inverse_gram.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: inverse_gram.len(),
+                actual: row.len(),
+                context: "Feature dimension doesn't match cached inverse Gram matrix".to_string(),
+            });
+        }
+
+        // variance = σ² * (1 + x̃ᵀ (X^T X)^{-1} x̃)
+        let mut quadratic_form = 0.0f32;
+        for (i, &row_i) in row.iter().enumerate() {
+            let mut acc = 0.0f32;
+            for (j, &row_j) in row.iter().enumerate() {
+                acc += inverse_gram[i][j] * row_j;
+            }
+            quadratic_form += row_i * acc;
+        }
+
+        Ok((mean, sigma_squared * (1.0 + quadratic_form)))
+    }
+}
+
+impl Metrics for LinearRegression {
+    fn mse(&self, predictions: &[f32], targets: &[f32]) -> Result<f32, ModelError> {
+        if predictions.is_empty() || targets.is_empty() {
+            return Err(ModelError::ValidationError("Empty predictions or targets".to_string()));
+        }
+        if predictions.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: predictions.len(),
+                actual: targets.len(),
+                context: "predictions vs targets".to_string(),
+            });
+        }
+
+        let sse: f32 = predictions.iter().zip(targets).map(|(p, t)| (p - t).powi(2)).sum();
+        Ok(sse / predictions.len() as f32)
+    }
+
+    fn rmse(&self, predictions: &[f32], targets: &[f32]) -> Result<f32, ModelError> {
+        Ok(self.mse(predictions, targets)?.sqrt())
+    }
+
+    fn mae(&self, predictions: &[f32], targets: &[f32]) -> Result<f32, ModelError> {
+        if predictions.is_empty() || targets.is_empty() {
+            return Err(ModelError::ValidationError("Empty predictions or targets".to_string()));
+        }
+        if predictions.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: predictions.len(),
+                actual: targets.len(),
+                context: "predictions vs targets".to_string(),
+            });
+        }
+
+        let sae: f32 = predictions.iter().zip(targets).map(|(p, t)| (p - t).abs()).sum();
+        Ok(sae / predictions.len() as f32)
+    }
+
+    fn r_squared(&self, predictions: &[f32], targets: &[f32]) -> Result<f32, ModelError> {
+        if predictions.is_empty() || targets.is_empty() {
+            return Err(ModelError::ValidationError("Empty predictions or targets".to_string()));
+        }
+        if predictions.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: predictions.len(),
+                actual: targets.len(),
+                context: "predictions vs targets".to_string(),
+            });
+        }
+
+        let mean_target = targets.iter().sum::<f32>() / targets.len() as f32;
+        let ss_total: f32 = targets.iter().map(|t| (t - mean_target).powi(2)).sum();
+        let ss_residual: f32 = predictions.iter().zip(targets).map(|(p, t)| (p - t).powi(2)).sum();
+
+        if ss_total.abs() < 1e-12 {
+            return Err(ModelError::ValidationError("Targets have zero variance; R² is undefined".to_string()));
+        }
+
+        Ok(1.0 - ss_residual / ss_total)
+    }
+}
+
+impl IncrementalModel for LinearRegression {
+    /// Update the model with a single pass of SGD over `(features, targets)`: for
+    /// each example, compute the gradient `-2 * x^T * (y - x^T * w)` and apply
+    /// `w <- w - learning_rate * grad`
+    ///
+    /// Requires a prior `train` call so the weight dimensionality (and bias layout)
+    /// is already known. Invalidates any cached OLS uncertainty estimates, since the
+    /// updated weights no longer solve the normal equations they were derived from.
+    fn update(&mut self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
+        if !self.trained {
+            return Err(ModelError::TrainingError(
+                "LinearRegression must be trained before incremental updates so its dimensionality is known".to_string(),
+            ));
+        }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Incremental update features vs targets".to_string(),
+            });
+        }
+
+        let expected_dim = if self.with_bias { self.weights.len() - 1 } else { self.weights.len() };
+        let offset = if self.with_bias { 1 } else { 0 };
+
+        for (feature, &target) in features.iter().zip(targets.iter()) {
+            if feature.dimension() != expected_dim {
+                return Err(ModelError::DimensionMismatch {
+                    expected: expected_dim,
+                    actual: feature.dimension(),
+                    context: "Incremental update feature dimension doesn't match model weights".to_string(),
+                });
+            }
+
+            let prediction = self.predict(feature)?;
+            let error = target - prediction;
+            let feature_array = feature.as_array();
+
+            if self.with_bias {
+                self.weights[0] += self.learning_rate * 2.0 * error;
+            }
+            for i in 0..expected_dim {
+                self.weights[i + offset] += self.learning_rate * 2.0 * error * feature_array[i];
+            }
+        }
+
+        self.inverse_gram = None;
+        self.residual_variance = None;
+
+        Ok(())
+    }
+
+    fn set_learning_rate(&mut self, rate: f32) -> Result<(), ModelError> {
+        if rate <= 0.0 {
+            return Err(ModelError::InvalidParameter("learning_rate must be positive".to_string()));
+        }
+
+        self.learning_rate = rate;
+        Ok(())
+    }
+
+    fn get_parameters(&self) -> Vec<f32> {
+        self.weights.clone()
+    }
+}
+
+/// Factory for `LinearRegression`, letting the continuous-learning hyperparameter
+/// tuner construct trial instances over a `[learning_rate, max_iterations]` space
+#[derive(Debug, Clone)]
+pub struct LinearRegressionFactory {
+    with_bias: bool,
+}
+
+impl LinearRegressionFactory {
+    /// Create a factory that always produces models with the given bias setting
+    pub fn new(with_bias: bool) -> Self {
+        Self { with_bias }
+    }
+}
+
+impl ModelFactory for LinearRegressionFactory {
+    fn create(&self) -> Box<dyn Model> {
+        Box::new(LinearRegression::new(self.with_bias, 0.01, 1000))
+    }
+
+    fn create_with_params(&self, params: &[f32]) -> Result<Box<dyn Model>, ModelError> {
+        match params {
+            [learning_rate, max_iterations] => {
+                if *learning_rate <= 0.0 {
+                    return Err(ModelError::InvalidParameter("learning_rate must be positive".to_string()));
+                }
+                Ok(Box::new(LinearRegression::new(self.with_bias, *learning_rate, max_iterations.max(1.0) as usize)))
+            }
+            _ => Err(ModelError::InvalidParameter(
+                "LinearRegressionFactory expects params [learning_rate, max_iterations]".to_string(),
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,4 +1000,371 @@ mod tests {
         // Expected: 1 + 2*3 + 3*4 = 19
         assert!((prediction - 19.0).abs() < 0.1, "Prediction should be close to 19.0");
     }
+
+    #[test]
+    fn test_train_weighted_downweights_outlier() {
+        // A tight cluster on y = 2x, plus one heavily down-weighted outlier
+        let features = vec![
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+            FeatureVector::new(vec![3.0]),
+            FeatureVector::new(vec![4.0]),
+            FeatureVector::new(vec![10.0]),
+        ];
+        let targets = vec![2.0, 4.0, 6.0, 8.0, 100.0];
+        let weights = vec![1.0, 1.0, 1.0, 1.0, 0.001];
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.train_weighted(&features, &targets, Some(&weights)).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!((prediction - 10.0).abs() < 1.0, "Prediction should follow the dominant cluster, got {}", prediction);
+    }
+
+    #[test]
+    fn test_validate_weighted_computes_weighted_mse() {
+        let mut model = LinearRegression::new(false, 0.01, 1000);
+        model.import_parameters(vec![1.0]).unwrap(); // predict(x) = x
+
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])];
+        let targets = vec![2.0, 2.0]; // errors: -1.0 and 0.0
+        let weights = vec![1.0, 3.0];
+
+        // Weighted MSE = (1*1.0 + 3*0.0) / (1+3) = 0.25
+        let mse = model.validate_weighted(&features, &targets, Some(&weights)).unwrap();
+        assert!((mse - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_linear_regression_factory_creates_model_with_requested_hyperparams() {
+        let factory = LinearRegressionFactory::new(true);
+
+        let mut model = factory.create_with_params(&[0.05, 500.0]).unwrap();
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])];
+        let targets = vec![3.0, 5.0];
+        model.train(&features, &targets).unwrap();
+
+        // The trial model should behave like any other trained LinearRegression
+        assert!(model.validate(&features, &targets).unwrap() < 1.0);
+    }
+
+    #[test]
+    fn test_linear_regression_factory_rejects_invalid_params() {
+        let factory = LinearRegressionFactory::new(true);
+
+        assert!(factory.create_with_params(&[0.0, 100.0]).is_err());
+        assert!(factory.create_with_params(&[0.1]).is_err());
+    }
+
+    #[test]
+    fn test_ridge_regularization_solves_a_singular_design_matrix() {
+        // Two perfectly collinear columns make X^T*X singular for plain OLS
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+            FeatureVector::new(vec![3.0, 3.0]),
+            FeatureVector::new(vec![4.0, 4.0]),
+        ];
+        let targets = vec![2.0, 4.0, 6.0, 8.0];
+
+        let mut model = LinearRegression::new(true, 0.01, 1000)
+            .with_regularization(Regularization::Ridge(0.1));
+        model.train(&features, &targets).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![5.0, 5.0])).unwrap();
+        assert!((prediction - 10.0).abs() < 1.0, "Prediction should be close to 10.0, got {}", prediction);
+    }
+
+    #[test]
+    fn test_lasso_regularization_zeros_out_an_irrelevant_feature() {
+        // y depends only on x1; x2 is pure noise and should be driven to (near) zero
+        let features = vec![
+            FeatureVector::new(vec![1.0, 5.0]),
+            FeatureVector::new(vec![2.0, -3.0]),
+            FeatureVector::new(vec![3.0, 2.0]),
+            FeatureVector::new(vec![4.0, -1.0]),
+            FeatureVector::new(vec![5.0, 4.0]),
+        ];
+        let targets = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+
+        let mut model = LinearRegression::new(false, 0.01, 1000)
+            .with_regularization(Regularization::Lasso(1.0));
+        model.train(&features, &targets).unwrap();
+
+        let weights = model.export_parameters().unwrap();
+        assert!(weights[1].abs() < 0.1, "Irrelevant feature's weight should be near zero, got {}", weights[1]);
+    }
+
+    #[test]
+    fn test_elastic_net_regularization_shrinks_weights_without_full_sparsity() {
+        let features = vec![
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+            FeatureVector::new(vec![3.0]),
+            FeatureVector::new(vec![4.0]),
+        ];
+        let targets = vec![2.0, 4.0, 6.0, 8.0];
+
+        let mut unpenalized = LinearRegression::new(false, 0.01, 1000);
+        unpenalized.train(&features, &targets).unwrap();
+        let unpenalized_weight = unpenalized.export_parameters().unwrap()[0];
+
+        let mut model = LinearRegression::new(false, 0.01, 1000)
+            .with_regularization(Regularization::ElasticNet { l1: 0.1, l2: 0.1 });
+        model.train(&features, &targets).unwrap();
+        let penalized_weight = model.export_parameters().unwrap()[0];
+
+        assert!(penalized_weight.abs() < unpenalized_weight.abs(), "Penalized weight should shrink toward zero");
+        assert!(penalized_weight.abs() > 0.1, "Elastic-net shouldn't fully zero out a relevant feature here");
+    }
+
+    #[test]
+    fn test_regularization_round_trips_through_save_and_load() {
+        let mut model = LinearRegression::new(true, 0.01, 1000)
+            .with_regularization(Regularization::ElasticNet { l1: 0.2, l2: 0.3 });
+        model.train(&[FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])], &[3.0, 5.0]).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_regularized_linear_regression.json");
+        model.save(path.to_str().unwrap()).unwrap();
+
+        let mut loaded = LinearRegression::new(true, 0.01, 1000);
+        loaded.load(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.regularization, Regularization::ElasticNet { l1: 0.2, l2: 0.3 });
+    }
+
+    #[test]
+    fn test_predict_with_variance_shrinks_as_training_data_grows() {
+        // y = 2x + 3 with a little noise, fit via plain OLS
+        let features = vec![
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+            FeatureVector::new(vec![3.0]),
+            FeatureVector::new(vec![4.0]),
+        ];
+        let targets = vec![5.1, 6.9, 9.2, 10.8];
+
+        let mut small_model = LinearRegression::new(true, 0.01, 1000);
+        small_model.train(&features, &targets).unwrap();
+        let (_, small_variance) = small_model.predict_with_variance(&FeatureVector::new(vec![5.0])).unwrap();
+
+        let mut more_features = features.clone();
+        let mut more_targets = targets.clone();
+        more_features.extend(vec![FeatureVector::new(vec![5.0]), FeatureVector::new(vec![6.0])]);
+        more_targets.extend(vec![13.0, 15.1]);
+
+        let mut large_model = LinearRegression::new(true, 0.01, 1000);
+        large_model.train(&more_features, &more_targets).unwrap();
+        let (_, large_variance) = large_model.predict_with_variance(&FeatureVector::new(vec![5.0])).unwrap();
+
+        assert!(small_variance > 0.0);
+        assert!(large_variance < small_variance, "More training data should narrow the predictive variance");
+    }
+
+    #[test]
+    fn test_predict_with_variance_fails_without_a_cached_ols_fit() {
+        // Lasso fits via coordinate descent, which caches no Gram matrix
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0]), FeatureVector::new(vec![3.0])];
+        let targets = vec![2.0, 4.0, 6.0];
+
+        let mut model = LinearRegression::new(false, 0.01, 1000)
+            .with_regularization(Regularization::Lasso(0.1));
+        model.train(&features, &targets).unwrap();
+
+        assert!(model.predict_with_variance(&FeatureVector::new(vec![4.0])).is_err());
+    }
+
+    #[test]
+    fn test_explain_contributions_sum_to_prediction_minus_baseline() {
+        // y = 2*x1 + 3*x2 + 1
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 1.0]),
+            FeatureVector::new(vec![1.0, 2.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+        ];
+        let targets = vec![6.0, 8.0, 9.0, 11.0];
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.train(&features, &targets).unwrap();
+
+        let query = FeatureVector::new(vec![3.0, 4.0]);
+        let contributions = model.explain(&query).unwrap();
+        assert_eq!(contributions.len(), 2);
+
+        let baseline = model.predict(&FeatureVector::new(vec![1.5, 1.5])).unwrap(); // training feature means
+        let prediction = model.predict(&query).unwrap();
+        let reconstructed: f32 = baseline + contributions.iter().sum::<f32>();
+
+        assert!((reconstructed - prediction).abs() < 1e-3, "baseline + contributions should equal the prediction");
+    }
+
+    #[test]
+    fn test_explain_rejects_an_untrained_model() {
+        let model = LinearRegression::new(true, 0.01, 1000);
+        assert!(model.explain(&FeatureVector::new(vec![1.0])).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_json_round_trips_via_the_default_format() {
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.train(&[FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])], &[3.0, 5.0]).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_linear_regression_default.json");
+        model.save(path.to_str().unwrap()).unwrap();
+
+        let mut loaded = LinearRegression::new(true, 0.01, 1000);
+        loaded.load(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.export_parameters().unwrap(), model.export_parameters().unwrap());
+    }
+
+    #[cfg(feature = "bincode-format")]
+    #[test]
+    fn test_save_as_and_load_from_round_trip_bincode() {
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.train(&[FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])], &[3.0, 5.0]).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_linear_regression.bin");
+        model.save_as(path.to_str().unwrap(), SerializationFormat::Bincode).unwrap();
+
+        let mut loaded = LinearRegression::new(true, 0.01, 1000);
+        loaded.load_from(path.to_str().unwrap(), SerializationFormat::Auto).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.export_parameters().unwrap(), model.export_parameters().unwrap());
+    }
+
+    #[cfg(feature = "msgpack-format")]
+    #[test]
+    fn test_save_as_and_load_from_round_trip_messagepack() {
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.train(&[FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])], &[3.0, 5.0]).unwrap();
+
+        let path = std::env::temp_dir().join("continuum_test_linear_regression.msgpack");
+        model.save_as(path.to_str().unwrap(), SerializationFormat::MessagePack).unwrap();
+
+        let mut loaded = LinearRegression::new(true, 0.01, 1000);
+        loaded.load_from(path.to_str().unwrap(), SerializationFormat::Auto).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.export_parameters().unwrap(), model.export_parameters().unwrap());
+    }
+
+    #[test]
+    fn test_update_nudges_weights_toward_new_data() {
+        // Pretrain on y = 2x, then push it toward y = 4x with incremental updates
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0]), FeatureVector::new(vec![3.0])];
+        let targets = vec![2.0, 4.0, 6.0];
+
+        let mut model = LinearRegression::new(false, 0.01, 1000);
+        model.train(&features, &targets).unwrap();
+        let before = model.get_parameters()[0];
+
+        model.set_learning_rate(0.01).unwrap();
+        let new_features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0]), FeatureVector::new(vec![3.0])];
+        let new_targets = vec![4.0, 8.0, 12.0];
+        for _ in 0..200 {
+            model.update(&new_features, &new_targets).unwrap();
+        }
+
+        let after = model.get_parameters()[0];
+        assert!(after > before, "Weight should move toward the new data's steeper slope, before={} after={}", before, after);
+        assert!((after - 4.0).abs() < 0.5, "Weight should converge close to 4.0, got {}", after);
+    }
+
+    #[test]
+    fn test_update_rejects_an_untrained_model() {
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        let result = model.update(&[FeatureVector::new(vec![1.0])], &[2.0]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_update_rejects_mismatched_feature_dimension() {
+        let mut model = LinearRegression::new(false, 0.01, 1000);
+        model.train(&[FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])], &[2.0, 4.0]).unwrap();
+
+        let result = model.update(&[FeatureVector::new(vec![1.0, 2.0])], &[3.0]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_set_learning_rate_rejects_non_positive_values() {
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        assert!(model.set_learning_rate(0.0).is_err());
+        assert!(model.set_learning_rate(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_train_with_early_stopping_fits_and_reports_validation_metrics() {
+        // y = 2*x + 3, noise-free so the validation split should fit almost perfectly
+        let features: Vec<FeatureVector> = (1..=200).map(|i| FeatureVector::new(vec![i as f32 * 0.05])).collect();
+        let targets: Vec<f32> = features.iter().map(|f| 2.0 * f.as_array()[0] + 3.0).collect();
+
+        let mut model = LinearRegression::new(true, 0.01, 20000);
+        let summary = model
+            .train_with_early_stopping(&features, &targets, EarlyStopping { patience: 10, min_delta: 1e-6, val_fraction: 0.3 })
+            .unwrap();
+
+        assert!(summary.stopped_at <= 20000);
+        assert!(summary.r_squared > 0.9, "r_squared should be close to 1.0, got {}", summary.r_squared);
+        assert!((summary.rmse * summary.rmse - summary.mse).abs() < 1e-3, "rmse should be sqrt(mse)");
+
+        let prediction = model.predict(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!((prediction - 13.0).abs() < 0.5, "Restored best weights should still predict close to the true line");
+    }
+
+    #[test]
+    fn test_train_with_early_stopping_rejects_invalid_val_fraction() {
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])];
+        let targets = vec![2.0, 4.0];
+
+        let mut model = LinearRegression::new(true, 0.01, 100);
+        let result = model.train_with_early_stopping(
+            &features,
+            &targets,
+            EarlyStopping { patience: 5, min_delta: 1e-4, val_fraction: 1.0 },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_train_with_early_stopping_rejects_empty_data() {
+        let mut model = LinearRegression::new(true, 0.01, 100);
+        let result = model.train_with_early_stopping(
+            &[],
+            &[],
+            EarlyStopping { patience: 5, min_delta: 1e-4, val_fraction: 0.2 },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_metrics_mse_rmse_mae_r_squared() {
+        let model = LinearRegression::new(true, 0.01, 100);
+        let predictions = vec![1.0, 2.0, 3.0];
+        let targets = vec![1.0, 2.0, 5.0];
+
+        // Errors are [0, 0, -2]
+        assert!((model.mse(&predictions, &targets).unwrap() - (4.0 / 3.0)).abs() < 1e-5);
+        assert!((model.mae(&predictions, &targets).unwrap() - (2.0 / 3.0)).abs() < 1e-5);
+        assert!((model.rmse(&predictions, &targets).unwrap() - (4.0f32 / 3.0).sqrt()).abs() < 1e-5);
+
+        // mean(targets) = 8/3, ss_total = (1-8/3)^2 + (2-8/3)^2 + (5-8/3)^2 = 8.6667
+        // ss_residual = 4.0, so r_squared = 1 - 4.0/8.6667
+        let r_squared = model.r_squared(&predictions, &targets).unwrap();
+        assert!((r_squared - (1.0 - 4.0 / (26.0 / 3.0))).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_metrics_reject_mismatched_lengths() {
+        let model = LinearRegression::new(true, 0.01, 100);
+        assert!(model.mse(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(model.mae(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(model.rmse(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(model.r_squared(&[1.0, 2.0], &[1.0]).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod linears;
+pub mod ridge;
+pub mod pca;
+pub mod feature_extraction;
+pub mod classification;
+pub mod anomaly;
@@ -0,0 +1,180 @@
+use ndarray::{Array1, Array2, Axis};
+use ndarray_linalg::SVD;
+use serde::{Serialize, Deserialize};
+
+use crate::traits::features::FeatureVector;
+use crate::traits::model::ModelError;
+
+/// PCA feature transformer: centers inputs and projects them onto the top
+/// principal components of a fitted training design matrix
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PcaTransform {
+    /// Number of components requested; capped by `fit` at the input dimensionality
+    n_components: usize,
+    /// Per-feature mean learned during `fit`
+    mean: Option<Vec<f32>>,
+    /// Principal components, stored row-major as `n_components x n_features`
+    components: Option<Vec<f32>>,
+    /// Dimensionality of the input space this transform was fitted on
+    n_features: usize,
+}
+
+impl PcaTransform {
+    /// Create a new, unfitted PCA transform targeting `n_components`
+    pub fn new(n_components: usize) -> Self {
+        Self {
+            n_components,
+            mean: None,
+            components: None,
+            n_features: 0,
+        }
+    }
+
+    /// Whether `fit` has been called and this transform is ready to use
+    pub fn is_fitted(&self) -> bool {
+        self.mean.is_some() && self.components.is_some()
+    }
+
+    /// Fit the mean and top principal components from a training design matrix
+    ///
+    /// Computed via the SVD of the centered data rather than forming the
+    /// covariance matrix directly, which is both cheaper and more numerically stable.
+    pub fn fit(&mut self, features: &[FeatureVector]) -> Result<(), ModelError> {
+        if features.is_empty() {
+            return Err(ModelError::TrainingError("Cannot fit PCA on empty data".to_string()));
+        }
+
+        let n_samples = features.len();
+        let n_features = features[0].dimension();
+
+        let mut x = Array2::<f32>::zeros((n_samples, n_features));
+        for (i, feature) in features.iter().enumerate() {
+            let arr = feature.as_array();
+            for j in 0..n_features {
+                x[[i, j]] = arr[j];
+            }
+        }
+
+        let mean = x.mean_axis(Axis(0)).unwrap_or_else(|| Array1::zeros(n_features));
+        let centered = &x - &mean.broadcast((n_samples, n_features)).unwrap();
+
+        let (_, _s, vt_opt) = centered
+            .svd(false, true)
+            .map_err(|e| ModelError::TrainingError(format!("PCA SVD failed: {}", e)))?;
+        let vt = vt_opt.ok_or_else(|| ModelError::TrainingError("PCA SVD did not return V^T".to_string()))?;
+
+        let keep = self.n_components.min(vt.nrows());
+        let components = vt.slice(ndarray::s![..keep, ..]);
+
+        self.mean = Some(mean.to_vec());
+        self.components = Some(components.iter().copied().collect());
+        self.n_features = n_features;
+        self.n_components = keep;
+
+        Ok(())
+    }
+
+    /// Project a single feature vector onto the fitted principal components
+    pub fn transform(&self, feature: &FeatureVector) -> Result<FeatureVector, ModelError> {
+        let mean = self.mean.as_ref().ok_or_else(|| {
+            ModelError::InvalidParameter("PCA transform called before fit".to_string())
+        })?;
+        let components = self.components.as_ref().unwrap();
+
+        if feature.dimension() != self.n_features {
+            return Err(ModelError::DimensionMismatch {
+                expected: self.n_features,
+                actual: feature.dimension(),
+                context: "PCA transform input".to_string(),
+            });
+        }
+
+        let centered: Vec<f32> = feature
+            .as_array()
+            .iter()
+            .zip(mean.iter())
+            .map(|(x, m)| x - m)
+            .collect();
+
+        let mut projected = vec![0.0f32; self.n_components];
+        for (k, slot) in projected.iter_mut().enumerate() {
+            let row = &components[k * self.n_features..(k + 1) * self.n_features];
+            *slot = row.iter().zip(centered.iter()).map(|(c, x)| c * x).sum();
+        }
+
+        Ok(FeatureVector::new(projected))
+    }
+
+    /// Project multiple feature vectors, preserving order
+    pub fn transform_batch(&self, features: &[FeatureVector]) -> Result<Vec<FeatureVector>, ModelError> {
+        features.iter().map(|f| self.transform(f)).collect()
+    }
+
+    /// Approximately reconstruct the original feature space from a projected vector
+    pub fn inverse_transform(&self, projected: &FeatureVector) -> Result<FeatureVector, ModelError> {
+        let mean = self.mean.as_ref().ok_or_else(|| {
+            ModelError::InvalidParameter("PCA inverse_transform called before fit".to_string())
+        })?;
+        let components = self.components.as_ref().unwrap();
+
+        if projected.dimension() != self.n_components {
+            return Err(ModelError::DimensionMismatch {
+                expected: self.n_components,
+                actual: projected.dimension(),
+                context: "PCA inverse_transform input".to_string(),
+            });
+        }
+
+        let p = projected.as_array();
+        let mut reconstructed = vec![0.0f32; self.n_features];
+        for (j, slot) in reconstructed.iter_mut().enumerate() {
+            let mut sum = mean[j];
+            for k in 0..self.n_components {
+                sum += components[k * self.n_features + j] * p[k];
+            }
+            *slot = sum;
+        }
+
+        Ok(FeatureVector::new(reconstructed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pca_fit_transform_roundtrip() {
+        // Points lying along y = x, so the first principal component should capture all variance
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+            FeatureVector::new(vec![3.0, 3.0]),
+            FeatureVector::new(vec![4.0, 4.0]),
+        ];
+
+        let mut pca = PcaTransform::new(1);
+        pca.fit(&features).unwrap();
+        assert!(pca.is_fitted());
+
+        let projected = pca.transform(&FeatureVector::new(vec![2.0, 2.0])).unwrap();
+        assert_eq!(projected.dimension(), 1);
+
+        let reconstructed = pca.inverse_transform(&projected).unwrap();
+        assert!((reconstructed.as_array()[0] - 2.0).abs() < 0.1);
+        assert!((reconstructed.as_array()[1] - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_pca_dimension_mismatch() {
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+        ];
+        let mut pca = PcaTransform::new(1);
+        pca.fit(&features).unwrap();
+
+        let result = pca.transform(&FeatureVector::new(vec![1.0, 2.0, 3.0]));
+        assert!(matches!(result, Err(ModelError::DimensionMismatch { .. })));
+    }
+}
@@ -1,11 +1,46 @@
-use ndarray::{Array1, Array2};
-use ndarray_linalg::Solve;
+use ndarray::{Array1, Array2, Axis};
+use ndarray_linalg::{Solve, SVD, QR};
+use rand::Rng;
+use rand_distr::StandardNormal;
 use serde::{Serialize, Deserialize};
-use std::fs::File;
-use std::io::{BufReader, BufWriter};
 
 use crate::traits::features::FeatureVector;
-use crate::traits::model::{Model, ModelError};
+use crate::traits::model::{read_model, write_model, Model, ModelError, IncrementalModel, SerializationFormat};
+use crate::models::pca::PcaTransform;
+
+fn default_rls_lambda() -> f32 {
+    1.0
+}
+
+fn default_rls_delta() -> f32 {
+    1.0
+}
+
+fn default_solver() -> RidgeSolver {
+    RidgeSolver::Auto
+}
+
+/// Strategy used to solve `(X^T*X + alpha*I) * w = X^T*y` for `RidgeRegression::train`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RidgeSolver {
+    /// Pick closed-form vs. gradient descent using the existing size heuristic
+    Auto,
+    /// Solve the normal equations directly (LU factorization via `ndarray-linalg::Solve`)
+    Cholesky,
+    /// Matrix-free conjugate gradient; never materializes `X^T*X`
+    ConjugateGradient,
+    /// Fixed-iteration gradient descent
+    GradientDescent,
+    /// Randomized truncated-SVD solver, for very wide/high-dimensional designs
+    RandomizedSvd {
+        /// Target rank to keep from the truncated spectrum
+        rank: usize,
+        /// Extra random directions sampled beyond `rank` to improve approximation accuracy
+        oversample: usize,
+        /// Number of power iterations used to sharpen the subspace estimate
+        power_iterations: usize,
+    },
+}
 
 /// Ridge regression model (Linear regression with L2 regularization)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +57,28 @@ pub struct RidgeRegression {
     max_iterations: usize,
     /// Whether the model has been trained
     trained: bool,
+    /// Exponential forgetting factor for recursive least squares (1.0 = no forgetting)
+    #[serde(default = "default_rls_lambda")]
+    rls_lambda: f32,
+    /// Initial scale for the RLS inverse-covariance matrix (P0 = I / delta)
+    #[serde(default = "default_rls_delta")]
+    rls_delta: f32,
+    /// Persistent RLS inverse-covariance matrix, flattened row-major (dim x dim)
+    #[serde(default)]
+    p_matrix: Option<Vec<f32>>,
+    /// Which solver `train` uses to fit the normal equations
+    #[serde(default = "default_solver")]
+    solver: RidgeSolver,
+    /// Convergence tolerance for the conjugate-gradient solver
+    #[serde(default = "default_cg_tol")]
+    cg_tol: f32,
+    /// Optional PCA preprocessing applied to features before fitting/predicting
+    #[serde(default)]
+    pca: Option<PcaTransform>,
+}
+
+fn default_cg_tol() -> f32 {
+    1e-6
 }
 
 impl RidgeRegression {
@@ -34,9 +91,142 @@ impl RidgeRegression {
             learning_rate,
             max_iterations,
             trained: false,
+            rls_lambda: default_rls_lambda(),
+            rls_delta: default_rls_delta(),
+            p_matrix: None,
+            solver: default_solver(),
+            cg_tol: default_cg_tol(),
+            pca: None,
         }
     }
-    
+
+    /// Select the solver used by `train` instead of the default `Auto` heuristic
+    pub fn with_solver(mut self, solver: RidgeSolver) -> Self {
+        self.solver = solver;
+        self
+    }
+
+    /// Enable PCA preprocessing: features are centered and projected onto the top
+    /// `n_components` principal components (learned during `train`) before every
+    /// fit and every prediction, so training and serving stay consistent
+    pub fn with_pca(mut self, n_components: usize) -> Self {
+        self.pca = Some(PcaTransform::new(n_components));
+        self
+    }
+
+    /// Configure this model to use recursive least squares for streaming updates
+    ///
+    /// `lambda` is the exponential forgetting factor (values below 1.0 down-weight
+    /// older samples); `delta` seeds the initial inverse-covariance matrix `P = I/delta`,
+    /// with the model's existing `alpha` playing the analogous role of a prior precision.
+    pub fn with_recursive_least_squares(mut self, lambda: f32, delta: f32) -> Self {
+        self.rls_lambda = lambda;
+        self.rls_delta = delta;
+        self
+    }
+
+    /// Update the model with a single sample using Recursive Least Squares (RLS)
+    ///
+    /// Maintains a persistent inverse-covariance matrix `P` and updates weights in
+    /// O(d^2) per sample, which lets continuous learning track streaming data without
+    /// a full batch retrain. `P` is (re)initialized to `I / rls_delta` the first time
+    /// this is called, or whenever its size no longer matches the feature dimension;
+    /// a feature whose dimension doesn't match already-trained weights is rejected with
+    /// `DimensionMismatch`. With PCA configured, the fitted projection is applied first.
+    pub fn fit_recursive(&mut self, feature: &FeatureVector, target: f32) -> Result<(), ModelError> {
+        let transformed;
+        let feature = if let Some(pca) = &self.pca {
+            transformed = pca.transform(feature)?;
+            &transformed
+        } else {
+            feature
+        };
+
+        let dim = if self.with_bias { feature.dimension() + 1 } else { feature.dimension() };
+
+        // A model already trained (by a batch fit or a prior fit_recursive) has
+        // committed weights of a known dimension -- a mismatch here means the caller
+        // is feeding incompatible features, not that RLS needs to (re)start from zero
+        if self.trained && self.weights.len() != dim {
+            return Err(ModelError::DimensionMismatch {
+                expected: self.weights.len(),
+                actual: dim,
+                context: "RLS update feature dimension doesn't match model weights".to_string(),
+            });
+        }
+
+        let needs_init = match &self.p_matrix {
+            Some(p) => p.len() != dim * dim,
+            None => true,
+        };
+
+        if needs_init {
+            let mut p = vec![0.0f32; dim * dim];
+            for i in 0..dim {
+                p[i * dim + i] = 1.0 / self.rls_delta;
+            }
+            self.p_matrix = Some(p);
+
+            // Only a genuinely never-fit model starts from zero weights; a model
+            // that already has weights from a batch train() keeps them and just
+            // gets a fresh P matrix, so the very first online update after a batch
+            // fit refines the existing fit instead of discarding it
+            if !self.trained {
+                self.weights = vec![0.0; dim];
+            }
+        }
+
+        // Build the augmented feature vector (with bias term if configured)
+        let mut x = Vec::with_capacity(dim);
+        if self.with_bias {
+            x.push(1.0);
+        }
+        x.extend(feature.as_array().iter().copied());
+
+        let p = self.p_matrix.as_mut().unwrap();
+
+        // P * x (also equal to x^T * P since P is symmetric)
+        let mut px = vec![0.0f32; dim];
+        for i in 0..dim {
+            let mut sum = 0.0;
+            for j in 0..dim {
+                sum += p[i * dim + j] * x[j];
+            }
+            px[i] = sum;
+        }
+
+        let xt_p_x: f32 = x.iter().zip(px.iter()).map(|(xi, pxi)| xi * pxi).sum();
+        let denom = self.rls_lambda + xt_p_x;
+
+        if denom.abs() < 1e-8 {
+            return Err(ModelError::TrainingError(
+                "RLS update denominator (lambda + x^T P x) is too close to zero".to_string(),
+            ));
+        }
+
+        // Gain vector: k = P*x / denom
+        let k: Vec<f32> = px.iter().map(|v| v / denom).collect();
+
+        // Prediction error against the current weights
+        let y_hat: f32 = x.iter().zip(self.weights.iter()).map(|(xi, wi)| xi * wi).sum();
+        let error = target - y_hat;
+
+        // w = w + k * error
+        for i in 0..dim {
+            self.weights[i] += k[i] * error;
+        }
+
+        // P = (P - k * x^T * P) / lambda
+        for i in 0..dim {
+            for j in 0..dim {
+                p[i * dim + j] = (p[i * dim + j] - k[i] * px[j]) / self.rls_lambda;
+            }
+        }
+
+        self.trained = true;
+        Ok(())
+    }
+
     /// Create design matrix from feature vectors
     fn create_design_matrix(&self, features: &[FeatureVector]) -> Array2<f32> {
         let n_samples = features.len();
@@ -132,6 +322,300 @@ impl RidgeRegression {
         self.trained = true;
         Ok(())
     }
+
+    /// Train using matrix-free conjugate gradient, without ever materializing `X^T*X`
+    ///
+    /// Solves `(X^T*X + alpha*I) * w = X^T*y` by only ever evaluating the
+    /// matrix-vector product `X^T*(X*p) + alpha*p` for the current search direction
+    /// `p`. This avoids forming the `d x d` Gram matrix, which matters for wide
+    /// designs where `d` is large.
+    fn fit_conjugate_gradient(&mut self, x: &Array2<f32>, y: &Array1<f32>) -> Result<(), ModelError> {
+        let n_features = x.ncols();
+        let offset = if self.with_bias { 1 } else { 0 };
+
+        // Applies (X^T*X + alpha*I) * v, skipping the bias coordinate in the penalty
+        let apply_a = |v: &Array1<f32>, alpha: f32| -> Array1<f32> {
+            let xv = x.dot(v);
+            let mut result = x.t().dot(&xv);
+            for i in offset..n_features {
+                result[i] += alpha * v[i];
+            }
+            result
+        };
+
+        let b = x.t().dot(y);
+        let mut w = Array1::<f32>::zeros(n_features);
+        let mut r = &b - &apply_a(&w, self.alpha);
+        let mut p = r.clone();
+        let mut rs_old: f32 = r.dot(&r);
+
+        for _ in 0..self.max_iterations {
+            if rs_old.sqrt() < self.cg_tol {
+                break;
+            }
+
+            let ap = apply_a(&p, self.alpha);
+            let pap = p.dot(&ap);
+            if pap.abs() < 1e-12 {
+                break;
+            }
+
+            let step = rs_old / pap;
+            w = &w + &(&p * step);
+            r = &r - &(&ap * step);
+
+            let rs_new = r.dot(&r);
+            let beta = rs_new / rs_old;
+            p = &r + &(&p * beta);
+            rs_old = rs_new;
+        }
+
+        self.weights = w.to_vec();
+        self.trained = true;
+        Ok(())
+    }
+
+    /// Fit with automatic alpha selection via leave-one-out / generalized cross-validation
+    ///
+    /// Computes a single SVD of the (mean-centered) design matrix and evaluates every
+    /// candidate in `alphas` against it: for `X = U*diag(s)*V^T`, the ridge shrinkage
+    /// factors `d_i = s_i^2 / (s_i^2 + alpha)` give both the fitted values and the hat-matrix
+    /// diagonal without refitting, so `LOO(alpha) = mean(((y_i - yhat_i) / (1 - h_ii))^2)`.
+    /// The `alpha` minimizing this score is kept on `self.alpha` along with its weights.
+    pub fn fit_cv(&mut self, features: &[FeatureVector], targets: &[f32], alphas: &[f32]) -> Result<(), ModelError> {
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
+        }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "RidgeCV features vs targets".to_string(),
+            });
+        }
+
+        if alphas.is_empty() {
+            return Err(ModelError::InvalidParameter(
+                "RidgeCV requires at least one candidate alpha".to_string(),
+            ));
+        }
+
+        // Fit and apply PCA preprocessing, if configured, as `train` does
+        let transformed;
+        let features = if let Some(pca) = self.pca.as_mut() {
+            pca.fit(features)?;
+            transformed = pca.transform_batch(features)?;
+            transformed.as_slice()
+        } else {
+            features
+        };
+
+        let n_samples = features.len();
+        let n_features = features[0].dimension();
+
+        // Raw (un-augmented) design matrix; the bias is handled via centering below
+        let mut x = Array2::<f32>::zeros((n_samples, n_features));
+        for (i, feature) in features.iter().enumerate() {
+            let arr = feature.as_array();
+            for j in 0..n_features {
+                x[[i, j]] = arr[j];
+            }
+        }
+        let y = Array1::from(targets.to_vec());
+
+        let (x_mean, y_mean) = if self.with_bias {
+            (x.mean_axis(Axis(0)).unwrap_or_else(|| Array1::zeros(n_features)), y.mean().unwrap_or(0.0))
+        } else {
+            (Array1::zeros(n_features), 0.0)
+        };
+
+        let x_centered = &x - &x_mean.broadcast((n_samples, n_features)).unwrap();
+        let y_centered = &y - y_mean;
+
+        let (u_opt, s, vt_opt) = x_centered
+            .svd(true, true)
+            .map_err(|e| ModelError::TrainingError(format!("SVD failed during RidgeCV: {}", e)))?;
+        let u = u_opt.ok_or_else(|| ModelError::TrainingError("SVD did not return U".to_string()))?;
+        let vt = vt_opt.ok_or_else(|| ModelError::TrainingError("SVD did not return V^T".to_string()))?;
+
+        let rank = s.len();
+        let u_r = u.slice(ndarray::s![.., ..rank]);
+        // Project y onto the left singular vectors once; reused for every candidate alpha
+        let uty: Array1<f32> = u_r.t().dot(&y_centered);
+        let u_sq = u_r.mapv(|v| v * v);
+
+        let mut best_alpha = alphas[0];
+        let mut best_score = f32::INFINITY;
+        let mut best_d = Array1::<f32>::zeros(rank);
+
+        for &alpha in alphas {
+            let d: Array1<f32> = s.mapv(|si| (si * si) / (si * si + alpha));
+            let fitted = u_r.dot(&(&d * &uty));
+            let h = u_sq.dot(&d);
+
+            let mut sum_sq = 0.0f32;
+            for i in 0..n_samples {
+                let denom = 1.0 - h[i];
+                if denom.abs() < 1e-6 {
+                    sum_sq = f32::INFINITY;
+                    break;
+                }
+                let resid = (y_centered[i] - fitted[i]) / denom;
+                sum_sq += resid * resid;
+            }
+            let score = sum_sq / n_samples as f32;
+
+            if score < best_score {
+                best_score = score;
+                best_alpha = alpha;
+                best_d = d;
+            }
+        }
+
+        let d_over_s: Array1<f32> = best_d
+            .iter()
+            .zip(s.iter())
+            .map(|(d, si)| if *si > 1e-12 { d / si } else { 0.0 })
+            .collect();
+        let coef = vt.slice(ndarray::s![..rank, ..]).t().dot(&(&d_over_s * &uty));
+
+        self.alpha = best_alpha;
+
+        if self.with_bias {
+            let intercept = y_mean - x_mean.dot(&coef);
+            let mut weights = Vec::with_capacity(n_features + 1);
+            weights.push(intercept);
+            weights.extend(coef.iter().copied());
+            self.weights = weights;
+        } else {
+            self.weights = coef.to_vec();
+        }
+
+        self.trained = true;
+        Ok(())
+    }
+
+    /// Fit using a randomized truncated SVD of the design matrix (already includes the
+    /// bias column when configured; that column's direction is projected out first so
+    /// the intercept stays unpenalized, as in the other solvers)
+    ///
+    /// Draws a random Gaussian test matrix to cheaply find an approximate subspace
+    /// spanning the dominant column space of `x`, refines it with `power_iterations`
+    /// power iterations (re-orthonormalizing between each via QR to avoid numerical
+    /// blow-up), then takes the exact SVD of the small projected matrix and applies
+    /// the usual ridge shrinkage `d_i = s_i / (s_i^2 + alpha)` on the truncated spectrum.
+    /// This trades a small amount of approximation error for solving ridge without ever
+    /// touching `X^T*X` on very high-dimensional designs.
+    fn fit_randomized_svd(
+        &mut self,
+        x: &Array2<f32>,
+        y: &Array1<f32>,
+        rank: usize,
+        oversample: usize,
+        power_iterations: usize,
+    ) -> Result<(), ModelError> {
+        let n_features = x.ncols();
+        let l = (rank + oversample).min(n_features).max(1);
+
+        // Project out the bias column (ones, or sqrt(weight) after sample weighting);
+        // the intercept is recovered from these projection coefficients at the end
+        let (x_fit, y_fit, x_bias_coef, y_bias_coef) = if self.with_bias {
+            let s = x.column(0).to_owned();
+            let ss = s.dot(&s).max(1e-12);
+            let x_coef = s.dot(x) / ss;
+            let y_coef = s.dot(y) / ss;
+            let x_fit = x - &s.view().insert_axis(Axis(1)).dot(&x_coef.view().insert_axis(Axis(0)));
+            (x_fit, y - &(&s * y_coef), Some(x_coef), y_coef)
+        } else {
+            (x.clone(), y.clone(), None, 0.0)
+        };
+        let (x, y) = (&x_fit, &y_fit);
+
+        let mut rng = rand::thread_rng();
+        let mut omega = Array2::<f32>::zeros((n_features, l));
+        for v in omega.iter_mut() {
+            *v = rng.sample(StandardNormal);
+        }
+
+        let mut y_sketch = x.dot(&omega);
+
+        for _ in 0..power_iterations {
+            let z = x.t().dot(&y_sketch);
+            y_sketch = x.dot(&z);
+            // Re-orthonormalize to keep the power iteration numerically stable
+            let (q, _) = y_sketch
+                .qr()
+                .map_err(|e| ModelError::TrainingError(format!("QR failed during randomized SVD power iteration: {}", e)))?;
+            y_sketch = q;
+        }
+
+        let (q, _) = y_sketch
+            .qr()
+            .map_err(|e| ModelError::TrainingError(format!("QR failed during randomized SVD: {}", e)))?;
+
+        // Project X onto the small subspace and take its exact SVD
+        let b = q.t().dot(x);
+        let (ub_opt, s, vt_opt) = b
+            .svd(true, true)
+            .map_err(|e| ModelError::TrainingError(format!("SVD failed during randomized SVD: {}", e)))?;
+        let ub = ub_opt.ok_or_else(|| ModelError::TrainingError("SVD did not return U".to_string()))?;
+        let vt = vt_opt.ok_or_else(|| ModelError::TrainingError("SVD did not return V^T".to_string()))?;
+
+        let keep = rank.min(s.len());
+        let u = q.dot(&ub.slice(ndarray::s![.., ..keep]));
+        let s_trunc = s.slice(ndarray::s![..keep]).to_owned();
+        let v = vt.slice(ndarray::s![..keep, ..]).t().to_owned();
+
+        // Ridge shrinkage on the truncated spectrum: w = V * diag(s/(s^2+alpha)) * U^T * y
+        let uty = u.t().dot(y);
+        let shrink: Array1<f32> = s_trunc.mapv(|si| si / (si * si + self.alpha));
+        let mut weights = v.dot(&(&shrink * &uty));
+        if let Some(x_coef) = x_bias_coef {
+            let intercept = y_bias_coef - x_coef.dot(&weights);
+            weights[0] += intercept;
+        }
+
+        self.weights = weights.to_vec();
+        self.trained = true;
+        Ok(())
+    }
+
+    /// Dispatch to the configured solver for an already-built design matrix/target pair
+    fn dispatch_fit(&mut self, x: Array2<f32>, y: Array1<f32>) -> Result<(), ModelError> {
+        match self.solver {
+            RidgeSolver::Auto => {
+                // Choose training method based on data size
+                if x.ncols() < 1000 && x.nrows() > x.ncols() {
+                    self.fit_closed_form(x, y)
+                } else {
+                    self.fit_gradient_descent(&x, &y)
+                }
+            }
+            RidgeSolver::Cholesky => self.fit_closed_form(x, y),
+            RidgeSolver::ConjugateGradient => self.fit_conjugate_gradient(&x, &y),
+            RidgeSolver::GradientDescent => self.fit_gradient_descent(&x, &y),
+            RidgeSolver::RandomizedSvd { rank, oversample, power_iterations } => {
+                self.fit_randomized_svd(&x, &y, rank, oversample, power_iterations)
+            }
+        }
+    }
+
+    /// Rescale each row of the design matrix and its target by `sqrt(weight)`
+    ///
+    /// Minimizing the unweighted least-squares objective on these rescaled rows is
+    /// equivalent to minimizing the weighted objective `sum(w_i * (y_i - x_i*w)^2)` on the
+    /// originals, so every existing solver can be reused unchanged for weighted fitting.
+    fn apply_sample_weights(x: &Array2<f32>, y: &Array1<f32>, sample_weights: &[f32]) -> (Array2<f32>, Array1<f32>) {
+        let mut x_weighted = x.clone();
+        let mut y_weighted = y.clone();
+        for (i, &w) in sample_weights.iter().enumerate() {
+            let sw = w.max(0.0).sqrt();
+            x_weighted.row_mut(i).mapv_inplace(|v| v * sw);
+            y_weighted[i] *= sw;
+        }
+        (x_weighted, y_weighted)
+    }
 }
 
 impl Model for RidgeRegression {
@@ -139,7 +623,7 @@ impl Model for RidgeRegression {
         if features.is_empty() || targets.is_empty() {
             return Err(ModelError::TrainingError("Empty training data".to_string()));
         }
-        
+
         if features.len() != targets.len() {
             return Err(ModelError::DimensionMismatch {
                 expected: features.len(),
@@ -147,26 +631,85 @@ impl Model for RidgeRegression {
                 context: "Number of feature vectors doesn't match number of targets".to_string(),
             });
         }
-        
+
+        // Fit and apply PCA preprocessing, if configured, before building the design matrix
+        let transformed;
+        let features = if let Some(pca) = self.pca.as_mut() {
+            pca.fit(features)?;
+            transformed = pca.transform_batch(features)?;
+            transformed.as_slice()
+        } else {
+            features
+        };
+
         // Create design matrix
         let x = self.create_design_matrix(features);
         let y = Array1::from(targets.to_vec());
-        
-        // Choose training method based on data size
-        if x.ncols() < 1000 && x.nrows() > x.ncols() {
-            // Use closed-form solution for smaller problems
-            self.fit_closed_form(x, y)
-        } else {
-            // Use gradient descent for larger problems
-            self.fit_gradient_descent(&x, &y)
+
+        self.dispatch_fit(x, y)
+    }
+
+    fn train_weighted(
+        &mut self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        let sample_weights = match sample_weights {
+            Some(w) => w,
+            None => return self.train(features, targets),
+        };
+
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
         }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Number of feature vectors doesn't match number of targets".to_string(),
+            });
+        }
+
+        if sample_weights.len() != features.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: sample_weights.len(),
+                context: "sample_weights doesn't match number of feature vectors".to_string(),
+            });
+        }
+
+        let transformed;
+        let features = if let Some(pca) = self.pca.as_mut() {
+            pca.fit(features)?;
+            transformed = pca.transform_batch(features)?;
+            transformed.as_slice()
+        } else {
+            features
+        };
+
+        let x = self.create_design_matrix(features);
+        let y = Array1::from(targets.to_vec());
+        let (x, y) = Self::apply_sample_weights(&x, &y, sample_weights);
+
+        self.dispatch_fit(x, y)
     }
     
     fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
         if !self.trained {
             return Err(ModelError::PredictionError("Model not trained".to_string()));
         }
-        
+
+        // Apply the same PCA projection learned during training
+        let transformed;
+        let feature = if let Some(pca) = &self.pca {
+            transformed = pca.transform(feature)?;
+            &transformed
+        } else {
+            feature
+        };
+
         let expected_dim = if self.with_bias {
             self.weights.len() - 1
         } else {
@@ -238,35 +781,104 @@ impl Model for RidgeRegression {
         let mse = sum_squared_error / predictions.len() as f32;
         Ok(mse)
     }
-    
-    fn save(&self, path: &str) -> Result<(), ModelError> {
-        let file = File::create(path)?;
-        let writer = BufWriter::new(file);
-        
-        match serde_json::to_writer(writer, self) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(ModelError::SerializationError(e.to_string())),
+
+    fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        let sample_weights = match sample_weights {
+            Some(w) => w,
+            None => return self.validate(features, targets),
+        };
+
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::ValidationError("Empty validation data".to_string()));
         }
-    }
-    
-    fn load(&mut self, path: &str) -> Result<(), ModelError> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        
-        match serde_json::from_reader(reader) {
-            Ok(model) => {
-                *self = model;
-                Ok(())
-            }
-            Err(e) => Err(ModelError::SerializationError(e.to_string())),
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "Validation features vs targets".to_string(),
+            });
         }
+
+        if sample_weights.len() != features.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: sample_weights.len(),
+                context: "sample_weights doesn't match number of feature vectors".to_string(),
+            });
+        }
+
+        let predictions = self.predict_batch(features)?;
+
+        let mut weighted_sum_squared_error = 0.0f32;
+        let mut weight_total = 0.0f32;
+        for i in 0..predictions.len() {
+            let error = predictions[i] - targets[i];
+            weighted_sum_squared_error += sample_weights[i] * error * error;
+            weight_total += sample_weights[i];
+        }
+
+        if weight_total.abs() < 1e-12 {
+            return Err(ModelError::ValidationError("Sum of sample weights is zero".to_string()));
+        }
+
+        Ok(weighted_sum_squared_error / weight_total)
     }
-    
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        write_model(self, path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        *self = read_model(path, format)?;
+        Ok(())
+    }
+
     fn clone_model(&self) -> Box<dyn Model> {
         Box::new(self.clone())
     }
 }
 
+/// Incremental updates via Recursive Least Squares, one sample at a time
+impl IncrementalModel for RidgeRegression {
+    fn update(&mut self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "RLS update features vs targets".to_string(),
+            });
+        }
+
+        for (feature, target) in features.iter().zip(targets.iter()) {
+            self.fit_recursive(feature, *target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the RLS forgetting factor (lambda); must be in (0.0, 1.0]
+    fn set_learning_rate(&mut self, rate: f32) -> Result<(), ModelError> {
+        if rate <= 0.0 || rate > 1.0 {
+            return Err(ModelError::InvalidParameter(
+                "RLS forgetting factor must be in (0.0, 1.0]".to_string(),
+            ));
+        }
+
+        self.rls_lambda = rate;
+        Ok(())
+    }
+
+    fn get_parameters(&self) -> Vec<f32> {
+        self.weights.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -369,4 +981,202 @@ mod tests {
         assert!(high_reg_weights[0].abs() < low_reg_weights[0].abs(),
                 "High regularization should result in smaller weights");
     }
+
+    #[test]
+    fn test_recursive_least_squares_converges() {
+        // y = 2*x + 1, fed one sample at a time via RLS
+        let mut model = RidgeRegression::new(true, 0.1, 0.01, 1000)
+            .with_recursive_least_squares(1.0, 0.01);
+
+        let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let ys: Vec<f32> = xs.iter().map(|x| 2.0 * x + 1.0).collect();
+
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            model.fit_recursive(&FeatureVector::new(vec![*x]), *y).unwrap();
+        }
+
+        let weights = model.export_parameters().unwrap();
+        assert!((weights[0] - 1.0).abs() < 0.5, "Bias should converge near 1.0");
+        assert!((weights[1] - 2.0).abs() < 0.5, "Coefficient should converge near 2.0");
+
+        let prediction = model.predict(&FeatureVector::new(vec![9.0])).unwrap();
+        assert!((prediction - 19.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_recursive_least_squares_refines_batch_fit_instead_of_discarding_it() {
+        // y = 2*x + 1, fit once in closed form, then handed a single streaming sample
+        let mut model = RidgeRegression::new(true, 0.01, 0.01, 1000)
+            .with_recursive_least_squares(1.0, 0.01);
+
+        let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let ys: Vec<f32> = xs.iter().map(|x| 2.0 * x + 1.0).collect();
+        let features: Vec<FeatureVector> = xs.iter().map(|x| FeatureVector::new(vec![*x])).collect();
+        model.train(&features, &ys).unwrap();
+
+        let batch_weights = model.export_parameters().unwrap();
+        assert!((batch_weights[0] - 1.0).abs() < 0.5, "Batch fit should already be close to the true bias");
+        assert!((batch_weights[1] - 2.0).abs() < 0.5, "Batch fit should already be close to the true coefficient");
+
+        // A single online update after the batch fit must refine these weights, not
+        // reset them to zero and relearn from one sample
+        model.fit_recursive(&FeatureVector::new(vec![6.0]), 13.0).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![9.0])).unwrap();
+        assert!((prediction - 19.0).abs() < 1.0, "Prediction should still reflect the batch fit, got {}", prediction);
+    }
+
+    #[test]
+    fn test_recursive_least_squares_rejects_bad_lambda() {
+        let mut model = RidgeRegression::new(true, 0.1, 0.01, 1000);
+        assert!(model.set_learning_rate(0.0).is_err());
+        assert!(model.set_learning_rate(1.5).is_err());
+        assert!(model.set_learning_rate(0.95).is_ok());
+    }
+
+    #[test]
+    fn test_conjugate_gradient_solver_matches_closed_form() {
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 1.0]),
+            FeatureVector::new(vec![1.0, 2.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+            FeatureVector::new(vec![3.0, 1.0]),
+        ];
+        let targets = vec![6.0, 8.0, 9.0, 11.0, 10.0];
+
+        let mut closed_form_model = RidgeRegression::new(true, 0.5, 0.01, 1000)
+            .with_solver(RidgeSolver::Cholesky);
+        closed_form_model.train(&features, &targets).unwrap();
+
+        let mut cg_model = RidgeRegression::new(true, 0.5, 0.01, 1000)
+            .with_solver(RidgeSolver::ConjugateGradient);
+        cg_model.train(&features, &targets).unwrap();
+
+        let closed_form_weights = closed_form_model.export_parameters().unwrap();
+        let cg_weights = cg_model.export_parameters().unwrap();
+
+        for (a, b) in closed_form_weights.iter().zip(cg_weights.iter()) {
+            assert!((a - b).abs() < 1e-2, "CG solver should match closed form: {} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_ridge_cv_selects_alpha_and_fits() {
+        let features = vec![
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+            FeatureVector::new(vec![3.0]),
+            FeatureVector::new(vec![4.0]),
+            FeatureVector::new(vec![5.0]),
+            FeatureVector::new(vec![6.0]),
+        ];
+        let targets = vec![3.0, 5.0, 7.0, 9.0, 11.0, 13.0]; // y = 2x + 1
+
+        let mut model = RidgeRegression::new(true, 1.0, 0.01, 1000);
+        let alphas = [0.001, 0.01, 0.1, 1.0, 10.0];
+        model.fit_cv(&features, &targets, &alphas).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![7.0])).unwrap();
+        assert!((prediction - 15.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_ridge_cv_rejects_empty_alphas() {
+        let mut model = RidgeRegression::new(true, 1.0, 0.01, 1000);
+        let features = vec![FeatureVector::new(vec![1.0])];
+        let targets = vec![1.0];
+        assert!(model.fit_cv(&features, &targets, &[]).is_err());
+    }
+
+    #[test]
+    fn test_randomized_svd_solver_approximates_closed_form() {
+        let features = vec![
+            FeatureVector::new(vec![1.0, 1.0]),
+            FeatureVector::new(vec![2.0, 1.0]),
+            FeatureVector::new(vec![1.0, 2.0]),
+            FeatureVector::new(vec![2.0, 2.0]),
+            FeatureVector::new(vec![3.0, 1.0]),
+            FeatureVector::new(vec![1.0, 3.0]),
+        ];
+        let targets = vec![6.0, 8.0, 9.0, 11.0, 10.0, 10.0];
+
+        let mut closed_form_model = RidgeRegression::new(true, 0.5, 0.01, 1000)
+            .with_solver(RidgeSolver::Cholesky);
+        closed_form_model.train(&features, &targets).unwrap();
+
+        let mut svd_model = RidgeRegression::new(true, 0.5, 0.01, 1000)
+            .with_solver(RidgeSolver::RandomizedSvd { rank: 3, oversample: 3, power_iterations: 3 });
+        svd_model.train(&features, &targets).unwrap();
+
+        let closed_form_weights = closed_form_model.export_parameters().unwrap();
+        let svd_weights = svd_model.export_parameters().unwrap();
+
+        for (a, b) in closed_form_weights.iter().zip(svd_weights.iter()) {
+            assert!((a - b).abs() < 0.1, "Randomized SVD solver should approximate closed form: {} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_ridge_with_pca_preprocessing() {
+        // Second feature is redundant (always 2x the first), so one component suffices
+        let features = vec![
+            FeatureVector::new(vec![1.0, 2.0]),
+            FeatureVector::new(vec![2.0, 4.0]),
+            FeatureVector::new(vec![3.0, 6.0]),
+            FeatureVector::new(vec![4.0, 8.0]),
+        ];
+        let targets = vec![3.0, 5.0, 7.0, 9.0]; // y = 2*x1 + 1
+
+        let mut model = RidgeRegression::new(true, 0.01, 0.01, 2000).with_pca(1);
+        model.train(&features, &targets).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![5.0, 10.0])).unwrap();
+        assert!((prediction - 11.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_train_weighted_downweights_outlier() {
+        // A tight cluster on y = 2x, plus one heavily down-weighted outlier
+        let features = vec![
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+            FeatureVector::new(vec![3.0]),
+            FeatureVector::new(vec![4.0]),
+            FeatureVector::new(vec![10.0]),
+        ];
+        let targets = vec![2.0, 4.0, 6.0, 8.0, 100.0];
+        let weights = vec![1.0, 1.0, 1.0, 1.0, 0.001];
+
+        let mut model = RidgeRegression::new(true, 0.01, 0.01, 1000);
+        model.train_weighted(&features, &targets, Some(&weights)).unwrap();
+
+        let prediction = model.predict(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!((prediction - 10.0).abs() < 1.0, "Prediction should follow the dominant cluster, got {}", prediction);
+    }
+
+    #[test]
+    fn test_train_weighted_rejects_mismatched_length() {
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])];
+        let targets = vec![1.0, 2.0];
+        let weights = vec![1.0];
+
+        let mut model = RidgeRegression::new(true, 0.1, 0.01, 1000);
+        let result = model.train_weighted(&features, &targets, Some(&weights));
+        assert!(matches!(result, Err(ModelError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_validate_weighted_computes_weighted_mse() {
+        let mut model = RidgeRegression::new(false, 0.0, 0.01, 1000);
+        model.import_parameters(vec![1.0]).unwrap(); // predict(x) = x
+
+        let features = vec![FeatureVector::new(vec![1.0]), FeatureVector::new(vec![2.0])];
+        let targets = vec![2.0, 2.0]; // errors: -1.0 and 0.0
+        let weights = vec![1.0, 3.0];
+
+        // Weighted MSE = (1*1.0 + 3*0.0) / (1+3) = 0.25
+        let mse = model.validate_weighted(&features, &targets, Some(&weights)).unwrap();
+        assert!((mse - 0.25).abs() < 1e-5);
+    }
 }
\ No newline at end of file
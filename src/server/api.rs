@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Instant;
+
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::sync::RwLock;
 
 use crate::traits::features::FeatureVector;
-use crate::traits::model::ModelError;
+use crate::traits::model::{Model, ModelError, SerializationFormat};
 use crate::server::server::ModelServer;
 use crate::server::continuous_learning::ContinuousLearningConfig;
+use crate::server::metrics::ApiMetrics;
+use crate::models::ridge::RidgeSolver;
 
 /// API errors
 #[derive(Error, Debug)]
@@ -20,6 +27,83 @@ pub enum ApiError {
 /// Result type for API operations
 pub type ApiResult<T> = Result<T, ApiError>;
 
+/// Constructs a model from its `ModelParameters`, registered per `model_type` string via
+/// `ContinuumApi::register_model_type`. Fallible since parameter parsing (e.g. a ridge
+/// solver name) can be rejected.
+type ModelFactoryFn = dyn Fn(&ModelParameters) -> ApiResult<Box<dyn Model>> + Send + Sync;
+
+/// Adapts a boxed `dyn Model` into a concrete `Model` impl so a factory-constructed
+/// model can still be registered with `ModelServer::register_model`, which needs
+/// `Model + Clone` to build an `AtomicModel<M>`. Lets `register_model_type`'s factories
+/// hand back a type-erased model without `ModelServer` knowing about the erasure at all.
+struct BoxedModel(Box<dyn Model>);
+
+impl Clone for BoxedModel {
+    fn clone(&self) -> Self {
+        BoxedModel(self.0.clone_model())
+    }
+}
+
+impl Model for BoxedModel {
+    fn train(&mut self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
+        self.0.train(features, targets)
+    }
+
+    fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
+        self.0.predict(feature)
+    }
+
+    fn predict_batch(&self, features: &[FeatureVector]) -> Result<Vec<f32>, ModelError> {
+        self.0.predict_batch(features)
+    }
+
+    fn warmup(&self) -> Result<(), ModelError> {
+        self.0.warmup()
+    }
+
+    fn export_parameters(&self) -> Result<Vec<f32>, ModelError> {
+        self.0.export_parameters()
+    }
+
+    fn import_parameters(&mut self, parameters: Vec<f32>) -> Result<(), ModelError> {
+        self.0.import_parameters(parameters)
+    }
+
+    fn validate(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError> {
+        self.0.validate(features, targets)
+    }
+
+    fn train_weighted(
+        &mut self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        self.0.train_weighted(features, targets, sample_weights)
+    }
+
+    fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        self.0.validate_weighted(features, targets, sample_weights)
+    }
+
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        self.0.save_as(path, format)
+    }
+
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        self.0.load_from(path, format)
+    }
+
+    fn clone_model(&self) -> Box<dyn Model> {
+        Box::new(self.clone())
+    }
+}
+
 /// Model parameters for initialization
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ModelParameters {
@@ -27,6 +111,19 @@ pub struct ModelParameters {
     pub learning_rate: Option<f32>,
     pub max_iterations: Option<usize>,
     pub regularization: Option<f32>,
+    /// Ridge solver to use: one of "auto", "cholesky", "conjugate_gradient", "gradient_descent"
+    pub solver: Option<String>,
+    /// Number of PCA components to project onto before fitting/predicting (ridge only)
+    pub pca_components: Option<usize>,
+    /// Class names, in score order; required for "logistic" and "svm" model types.
+    /// `regularization` doubles as each classifier's L2 penalty.
+    pub classes: Option<Vec<String>>,
+    /// Number of standard deviations from the running mean a point must exceed to
+    /// count as an anomaly; "anomaly" model type only, defaults to 3.0
+    pub z_threshold: Option<f32>,
+    /// EWMA window size controlling how quickly the running mean/variance adapts to
+    /// new points; "anomaly" model type only, defaults to 100
+    pub window_size: Option<usize>,
 }
 
 impl Default for ModelParameters {
@@ -36,10 +133,55 @@ impl Default for ModelParameters {
             learning_rate: Some(0.01),
             max_iterations: Some(1000),
             regularization: None,
+            solver: None,
+            pca_components: None,
+            classes: None,
+            z_threshold: None,
+            window_size: None,
         }
     }
 }
 
+/// Parse the `solver` string carried on `ModelParameters` into a `RidgeSolver`
+fn parse_ridge_solver(solver: &str) -> ApiResult<RidgeSolver> {
+    match solver {
+        "auto" => Ok(RidgeSolver::Auto),
+        "cholesky" => Ok(RidgeSolver::Cholesky),
+        "conjugate_gradient" => Ok(RidgeSolver::ConjugateGradient),
+        "gradient_descent" => Ok(RidgeSolver::GradientDescent),
+        other => Err(ApiError::InvalidInput(format!("Unknown ridge solver: {}", other))),
+    }
+}
+
+/// Pull the `classes` list required to register a "logistic" or "svm" model out of
+/// `ModelParameters`, rejecting a missing or degenerate (fewer than two classes) list
+fn classes_from_params(params: &ModelParameters) -> ApiResult<Vec<String>> {
+    let classes = params
+        .classes
+        .clone()
+        .ok_or_else(|| ApiError::InvalidInput("classifier model types require `classes`".to_string()))?;
+
+    if classes.len() < 2 {
+        return Err(ApiError::InvalidInput("classifier model types require at least 2 classes".to_string()));
+    }
+
+    Ok(classes)
+}
+
+/// Parse the `format` string carried on `save_model`/`load_model` into a
+/// `SerializationFormat`; `"auto"` infers the format from the file extension
+fn parse_serialization_format(format: &str) -> ApiResult<SerializationFormat> {
+    match format {
+        "json" => Ok(SerializationFormat::Json),
+        #[cfg(feature = "bincode-format")]
+        "bincode" => Ok(SerializationFormat::Bincode),
+        #[cfg(feature = "msgpack-format")]
+        "msgpack" => Ok(SerializationFormat::MessagePack),
+        "auto" => Ok(SerializationFormat::Auto),
+        other => Err(ApiError::InvalidInput(format!("Unknown serialization format: {}", other))),
+    }
+}
+
 /// Prediction response
 #[derive(Debug, Serialize)]
 pub struct PredictionResponse {
@@ -54,6 +196,31 @@ pub struct BatchPredictionResponse {
     pub model_version: usize,
 }
 
+/// Classification response: per-class scores rather than a single regression output
+#[derive(Debug, Serialize)]
+pub struct ClassificationResponse {
+    pub labels: Vec<String>,
+    pub scores: Vec<f32>,
+    pub model_version: usize,
+}
+
+/// Batch classification response
+#[derive(Debug, Serialize)]
+pub struct BatchClassificationResponse {
+    pub labels: Vec<String>,
+    pub scores: Vec<Vec<f32>>,
+    pub model_version: usize,
+}
+
+/// Anomaly-detection response: a continuous score alongside the threshold decision
+/// rather than a raw regression output or per-class scores
+#[derive(Debug, Serialize)]
+pub struct AnomalyResponse {
+    pub score: f32,
+    pub is_anomaly: bool,
+    pub model_version: usize,
+}
+
 /// Model information response
 #[derive(Debug, Serialize)]
 pub struct ModelInfo {
@@ -63,9 +230,54 @@ pub struct ModelInfo {
     pub stats: String,
 }
 
+/// Build the default `model_factories` registry: "linear" and "ridge", matching what
+/// `register_model` handled as a hardcoded match before factories were pluggable
+fn default_model_factories() -> HashMap<String, Box<ModelFactoryFn>> {
+    let mut factories: HashMap<String, Box<ModelFactoryFn>> = HashMap::new();
+
+    factories.insert(
+        "linear".to_string(),
+        Box::new(|params: &ModelParameters| -> ApiResult<Box<dyn Model>> {
+            Ok(Box::new(crate::models::linears::LinearRegression::new(
+                params.with_bias,
+                params.learning_rate.unwrap_or(0.01),
+                params.max_iterations.unwrap_or(1000),
+            )))
+        }),
+    );
+
+    factories.insert(
+        "ridge".to_string(),
+        Box::new(|params: &ModelParameters| -> ApiResult<Box<dyn Model>> {
+            let mut model = crate::models::ridge::RidgeRegression::new(
+                params.with_bias,
+                params.regularization.unwrap_or(0.1),
+                params.learning_rate.unwrap_or(0.01),
+                params.max_iterations.unwrap_or(1000),
+            );
+            if let Some(solver) = &params.solver {
+                model = model.with_solver(parse_ridge_solver(solver)?);
+            }
+            if let Some(n_components) = params.pca_components {
+                model = model.with_pca(n_components);
+            }
+            Ok(Box::new(model))
+        }),
+    );
+
+    factories
+}
+
 /// API for the ML system
 pub struct ContinuumApi {
     server: ModelServer,
+    /// Request counters/latency spanning every method on this API, independent of
+    /// the per-model stats tracked inside `ModelServer`/`AtomicModel`
+    metrics: ApiMetrics,
+    /// Model-type registry backing `register_model`; "linear"/"ridge" are seeded by
+    /// default, and `register_model_type` lets downstream crates add their own without
+    /// forking this match
+    model_factories: RwLock<HashMap<String, Box<ModelFactoryFn>>>,
 }
 
 impl ContinuumApi {
@@ -73,16 +285,48 @@ impl ContinuumApi {
     pub fn new(config: ContinuousLearningConfig) -> Self {
         Self {
             server: ModelServer::new(config),
+            metrics: ApiMetrics::new(),
+            model_factories: RwLock::new(default_model_factories()),
         }
     }
-    
+
     /// Create a new API instance with default configuration
     pub fn default() -> Self {
         Self {
             server: ModelServer::default(),
+            metrics: ApiMetrics::new(),
+            model_factories: RwLock::new(default_model_factories()),
         }
     }
-    
+
+    /// Register a constructor for a custom `model_type` string, so `register_model` can
+    /// build it without Continuum knowing about the concrete model type. Overwrites any
+    /// existing factory under the same name, including the built-in "linear"/"ridge".
+    pub async fn register_model_type<F>(&self, model_type: &str, factory: F)
+    where
+        F: Fn(&ModelParameters) -> ApiResult<Box<dyn Model>> + Send + Sync + 'static,
+    {
+        let mut factories = self.model_factories.write().await;
+        factories.insert(model_type.to_string(), Box::new(factory));
+    }
+
+    /// Time `f`, record the outcome (success/failure, elapsed latency) against
+    /// `self.metrics`, and return its result unchanged
+    async fn instrumented<T>(&self, model_name: Option<&str>, f: impl Future<Output = ApiResult<T>>) -> ApiResult<T> {
+        let start = Instant::now();
+        let result = f.await;
+        self.metrics.record_request(model_name, result.is_ok(), start.elapsed().as_micros() as usize);
+        result
+    }
+
+    /// Render this API's own request metrics plus every registered model's stats as a
+    /// single Prometheus text-exposition payload, ready to be served from `/metrics`
+    pub async fn metrics_snapshot(&self) -> String {
+        let mut out = self.metrics.to_prometheus();
+        out.push_str(&self.server.metrics_snapshot().await);
+        out
+    }
+
     /// Register a new model
     pub async fn register_model(
         &self,
@@ -90,73 +334,166 @@ impl ContinuumApi {
         model_type: &str,
         parameters: Option<ModelParameters>,
     ) -> ApiResult<()> {
-        let params = parameters.unwrap_or_default();
-        
-        match model_type {
-            "linear" => {
-                let model = crate::models::linears::LinearRegression::new(
-                    params.with_bias,
-                    params.learning_rate.unwrap_or(0.01),
-                    params.max_iterations.unwrap_or(1000),
-                );
-                self.server.register_model(name, model).await?;
-                Ok(())
-            }
-            "ridge" => {
-                let model = crate::models::ridge::RidgeRegression::new(
-                    params.with_bias,
-                    params.regularization.unwrap_or(0.1),
-                    params.learning_rate.unwrap_or(0.01),
-                    params.max_iterations.unwrap_or(1000),
-                );
-                self.server.register_model(name, model).await?;
-                Ok(())
+        self.instrumented(Some(name), async {
+            let params = parameters.unwrap_or_default();
+
+            match model_type {
+                "logistic" => {
+                    let classes = classes_from_params(&params)?;
+                    let classifier = crate::models::classification::LogisticRegression::new(
+                        classes,
+                        params.learning_rate.unwrap_or(0.01),
+                        params.max_iterations.unwrap_or(1000),
+                        params.regularization.unwrap_or(0.0),
+                    );
+                    self.server.register_classifier(name, Box::new(classifier)).await?;
+                    Ok(())
+                }
+                "svm" => {
+                    let classes = classes_from_params(&params)?;
+                    let classifier = crate::models::classification::LinearSvm::new(
+                        classes,
+                        params.learning_rate.unwrap_or(0.01),
+                        params.max_iterations.unwrap_or(1000),
+                        params.regularization.unwrap_or(0.0),
+                    );
+                    self.server.register_classifier(name, Box::new(classifier)).await?;
+                    Ok(())
+                }
+                "anomaly" => {
+                    let z_threshold = params.z_threshold.unwrap_or(3.0);
+                    let detector = crate::models::anomaly::AnomalyDetector::new(z_threshold, params.window_size.unwrap_or(100));
+                    self.server.register_anomaly_detector(name, detector, z_threshold).await?;
+                    Ok(())
+                }
+                _ => {
+                    let model = {
+                        let factories = self.model_factories.read().await;
+                        let factory = factories
+                            .get(model_type)
+                            .ok_or_else(|| ApiError::InvalidInput(format!("Unknown model type: {}", model_type)))?;
+                        factory(&params)?
+                    };
+                    self.server.register_model(name, BoxedModel(model)).await?;
+                    Ok(())
+                }
             }
-            _ => Err(ApiError::InvalidInput(format!("Unknown model type: {}", model_type))),
-        }
+        })
+        .await
     }
-    
+
     /// Make a prediction
+    ///
+    /// Transparently coalesced with other concurrently-arriving `predict`/`predict_batch`
+    /// calls for the same model: `ModelServer::predict` queues this request onto the
+    /// model's micro-batching dispatcher (see `crate::server::batching`) rather than
+    /// predicting inline, so high-QPS single-item traffic still amortizes lock
+    /// acquisition and matrix work across one underlying `predict_batch` call.
     pub async fn predict(&self, model_name: &str, features: Vec<f32>) -> ApiResult<PredictionResponse> {
-        let feature_vector = FeatureVector::new(features);
-        
-        // Get model for version info
-        let model = self.server.get_model(model_name).await?;
-        let version = model.get_version();
-        
-        // Make prediction
-        let prediction = self.server.predict(model_name, &feature_vector).await?;
-        
-        Ok(PredictionResponse {
-            prediction,
-            model_version: version,
+        self.instrumented(Some(model_name), async {
+            let feature_vector = FeatureVector::new(features);
+
+            // Get model for version info
+            let model = self.server.get_model(model_name).await?;
+            let version = model.get_version();
+
+            // Make prediction
+            let prediction = self.server.predict(model_name, &feature_vector).await?;
+
+            Ok(PredictionResponse {
+                prediction,
+                model_version: version,
+            })
         })
+        .await
     }
     
     /// Make batch predictions
+    ///
+    /// Each feature is queued onto the same per-model dispatcher `predict` uses, so a
+    /// batch call arriving alongside other single-item `predict` traffic is coalesced
+    /// with it rather than competing for a separate model lock acquisition.
     pub async fn predict_batch(
         &self,
         model_name: &str,
         features: Vec<Vec<f32>>,
     ) -> ApiResult<BatchPredictionResponse> {
-        let feature_vectors: Vec<FeatureVector> = features
-            .into_iter()
-            .map(FeatureVector::new)
-            .collect();
-        
-        // Get model for version info
-        let model = self.server.get_model(model_name).await?;
-        let version = model.get_version();
-        
-        // Make predictions
-        let predictions = self.server.predict_batch(model_name, &feature_vectors).await?;
-        
-        Ok(BatchPredictionResponse {
-            predictions,
-            model_version: version,
+        self.instrumented(Some(model_name), async {
+            let feature_vectors: Vec<FeatureVector> = features
+                .into_iter()
+                .map(FeatureVector::new)
+                .collect();
+
+            // Get model for version info
+            let model = self.server.get_model(model_name).await?;
+            let version = model.get_version();
+
+            // Make predictions
+            let predictions = self.server.predict_batch(model_name, &feature_vectors).await?;
+
+            Ok(BatchPredictionResponse {
+                predictions,
+                model_version: version,
+            })
         })
+        .await
     }
     
+    /// Train a registered "logistic" or "svm" classifier on a labeled batch, bumping
+    /// its served version on success. Unlike regression models, classifiers train
+    /// synchronously here rather than through the continuous-learning buffer/dispatcher.
+    pub async fn train_classifier(
+        &self,
+        model_name: &str,
+        features: Vec<Vec<f32>>,
+        labels: Vec<usize>,
+    ) -> ApiResult<()> {
+        self.instrumented(Some(model_name), async {
+            let feature_vectors: Vec<FeatureVector> = features.into_iter().map(FeatureVector::new).collect();
+            self.server.train_classifier(model_name, &feature_vectors, &labels).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Classify a single feature vector, returning per-class scores rather than a raw
+    /// regression output -- see `Classifier::predict_scores` for what the scores mean
+    /// for a given model type
+    pub async fn classify(&self, model_name: &str, features: Vec<f32>) -> ApiResult<ClassificationResponse> {
+        self.instrumented(Some(model_name), async {
+            let feature_vector = FeatureVector::new(features);
+            let (labels, scores, model_version) = self.server.classify(model_name, &feature_vector).await?;
+            Ok(ClassificationResponse { labels, scores, model_version })
+        })
+        .await
+    }
+
+    /// Classify multiple feature vectors
+    pub async fn classify_batch(
+        &self,
+        model_name: &str,
+        features: Vec<Vec<f32>>,
+    ) -> ApiResult<BatchClassificationResponse> {
+        self.instrumented(Some(model_name), async {
+            let feature_vectors: Vec<FeatureVector> = features.into_iter().map(FeatureVector::new).collect();
+            let (labels, scores, model_version) = self.server.classify_batch(model_name, &feature_vectors).await?;
+            Ok(BatchClassificationResponse { labels, scores, model_version })
+        })
+        .await
+    }
+
+    /// Score a feature vector against a registered "anomaly" detector, returning its
+    /// anomaly score alongside the threshold decision -- see
+    /// `crate::models::anomaly::AnomalyDetector` for how the score is computed
+    pub async fn detect_anomaly(&self, model_name: &str, features: Vec<f32>) -> ApiResult<AnomalyResponse> {
+        self.instrumented(Some(model_name), async {
+            let feature_vector = FeatureVector::new(features);
+            let (score, is_anomaly, model_version) = self.server.detect_anomaly(model_name, &feature_vector).await?;
+            Ok(AnomalyResponse { score, is_anomaly, model_version })
+        })
+        .await
+    }
+
     /// Add a training example
     pub async fn add_training_example(
         &self,
@@ -165,49 +502,123 @@ impl ContinuumApi {
         target: f32,
         is_validation: bool,
     ) -> ApiResult<()> {
-        let feature_vector = FeatureVector::new(features);
-        self.server.add_training_example(
-            model_name,
-            feature_vector,
-            target,
-            is_validation,
-        ).await?;
-        Ok(())
+        self.instrumented(Some(model_name), async {
+            let feature_vector = FeatureVector::new(features);
+            self.server.add_training_example(
+                model_name,
+                feature_vector,
+                target,
+                is_validation,
+            ).await?;
+            Ok(())
+        })
+        .await
     }
-    
+
+    /// Add a training example with an explicit importance weight
+    ///
+    /// Useful for imbalanced streams, recency weighting, or confidence-weighted labels.
+    pub async fn add_training_example_weighted(
+        &self,
+        model_name: &str,
+        features: Vec<f32>,
+        target: f32,
+        weight: f32,
+        is_validation: bool,
+    ) -> ApiResult<()> {
+        self.instrumented(Some(model_name), async {
+            let feature_vector = FeatureVector::new(features);
+            self.server.add_training_example_weighted(
+                model_name,
+                feature_vector,
+                target,
+                weight,
+                is_validation,
+            ).await?;
+            Ok(())
+        })
+        .await
+    }
+
     /// Manually trigger training for a model
     pub async fn train_model(&self, model_name: &str) -> ApiResult<()> {
-        self.server.train_now(model_name).await?;
-        Ok(())
+        self.instrumented(Some(model_name), async {
+            self.server.train_now(model_name).await?;
+            Ok(())
+        })
+        .await
     }
-    
+
+    /// Serialize a model's learned parameters (weights, bias, version, training stats)
+    /// to `path`, so it can later be restored via `load_model` -- e.g. to back up a
+    /// model outside the bounded ring `ContinuousLearningConfig::with_snapshot_dir`
+    /// maintains automatically. `format` is one of `"json"`, `"bincode"`, `"msgpack"`
+    pub async fn save_model(&self, model_name: &str, path: &str, format: &str) -> ApiResult<()> {
+        self.instrumented(Some(model_name), async {
+            let format = parse_serialization_format(format)?;
+            self.server.save_model(model_name, path, format).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Deserialize a model previously written by `save_model` back into `model_name`'s
+    /// serving and training state, inferring the format from `path`'s extension
+    pub async fn load_model(&self, model_name: &str, path: &str) -> ApiResult<()> {
+        self.instrumented(Some(model_name), async {
+            self.server.load_model(model_name, path, SerializationFormat::Auto).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Atomically roll a model's serving version back to `version`, recovering from
+    /// an on-disk snapshot if it's no longer retained in memory (see
+    /// `ContinuousLearningConfig::with_snapshot_dir`) -- a fast revert when
+    /// continuous learning has silently degraded a model, without re-registering and
+    /// re-training from scratch
+    pub async fn rollback_model(&self, model_name: &str, version: usize) -> ApiResult<usize> {
+        self.instrumented(Some(model_name), async {
+            Ok(self.server.rollback(model_name, version).await?)
+        })
+        .await
+    }
+
     /// Get model information
     pub async fn get_model_info(&self, model_name: &str) -> ApiResult<ModelInfo> {
-        let model = self.server.get_model(model_name).await?;
-        let stats = self.server.get_model_stats(model_name).await?;
-        
-        Ok(ModelInfo {
-            name: model_name.to_string(),
-            version: model.get_version(),
-            is_training: model.is_training(),
-            stats,
+        self.instrumented(Some(model_name), async {
+            let model = self.server.get_model(model_name).await?;
+            let stats = self.server.get_model_stats(model_name).await?;
+
+            Ok(ModelInfo {
+                name: model_name.to_string(),
+                version: model.get_version(),
+                is_training: model.is_training(),
+                stats,
+            })
         })
+        .await
     }
-    
+
     /// List all available models
     pub async fn list_models(&self) -> ApiResult<Vec<String>> {
-        Ok(self.server.list_models().await)
+        self.instrumented(None, async { Ok(self.server.list_models().await) }).await
     }
-    
+
     /// Start continuous learning
     pub async fn start_continuous_learning(&self) -> ApiResult<()> {
-        self.server.start_continuous_learning().await?;
-        Ok(())
+        self.instrumented(None, async {
+            self.server.start_continuous_learning().await?;
+            Ok(())
+        })
+        .await
     }
-    
+
     /// Stop continuous learning
     pub fn stop_continuous_learning(&self) -> ApiResult<()> {
+        let start = Instant::now();
         self.server.stop_continuous_learning();
+        self.metrics.record_request(None, true, start.elapsed().as_micros() as usize);
         Ok(())
     }
 }
@@ -240,7 +651,112 @@ mod tests {
             panic!("Expected InvalidInput error");
         }
     }
+
+    #[tokio::test]
+    async fn test_api_ridge_solver_selection() {
+        let api = ContinuumApi::default();
+
+        let params = ModelParameters {
+            solver: Some("conjugate_gradient".to_string()),
+            ..Default::default()
+        };
+        api.register_model("test_ridge", "ridge", Some(params)).await.unwrap();
+
+        let bad_params = ModelParameters {
+            solver: Some("not_a_solver".to_string()),
+            ..Default::default()
+        };
+        let result = api.register_model("test_ridge_bad", "ridge", Some(bad_params)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_register_model_type_adds_a_custom_model() {
+        let api = ContinuumApi::default();
+
+        api.register_model_type("always_zero", |params| {
+            Ok(Box::new(crate::models::linears::LinearRegression::new(
+                params.with_bias,
+                params.learning_rate.unwrap_or(0.01),
+                params.max_iterations.unwrap_or(1000),
+            )))
+        })
+        .await;
+
+        api.register_model("test_custom", "always_zero", None).await.unwrap();
+
+        let models = api.list_models().await.unwrap();
+        assert!(models.contains(&"test_custom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_api_register_model_type_can_fail_construction() {
+        let api = ContinuumApi::default();
+
+        api.register_model_type("picky", |_params| {
+            Err(ApiError::InvalidInput("picky refuses to be built".to_string()))
+        })
+        .await;
+
+        let result = api.register_model("test_picky", "picky", None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_ridge_with_pca() {
+        let api = ContinuumApi::default();
+
+        let params = ModelParameters {
+            pca_components: Some(1),
+            ..Default::default()
+        };
+        api.register_model("test_ridge_pca", "ridge", Some(params)).await.unwrap();
+
+        for i in 0..5 {
+            api.add_training_example(
+                "test_ridge_pca",
+                vec![i as f32, (i * 2) as f32],
+                (i * 2 + 1) as f32,
+                false,
+            ).await.unwrap();
+        }
+
+        api.train_model("test_ridge_pca").await.unwrap();
+        let response = api.predict("test_ridge_pca", vec![6.0, 12.0]).await.unwrap();
+        assert!(response.prediction.is_finite());
+    }
     
+    #[tokio::test]
+    async fn test_api_sample_weighted_training() {
+        let api = ContinuumApi::default();
+
+        api.register_model("test_weighted", "linear", None).await.unwrap();
+
+        // A cluster of heavily-weighted points near y = 2x, plus one lightly-weighted outlier
+        for i in 0..5 {
+            api.add_training_example_weighted(
+                "test_weighted",
+                vec![i as f32],
+                (i * 2) as f32,
+                5.0,
+                false,
+            ).await.unwrap();
+        }
+        api.add_training_example_weighted(
+            "test_weighted",
+            vec![10.0],
+            100.0,
+            0.01,
+            false,
+        ).await.unwrap();
+
+        api.train_model("test_weighted").await.unwrap();
+
+        // The heavily-weighted cluster should dominate the fit
+        let response = api.predict("test_weighted", vec![6.0]).await.unwrap();
+        assert!((response.prediction - 12.0).abs() < 2.0);
+    }
+
     #[tokio::test]
     async fn test_api_model_lifecycle() {
         let api = ContinuumApi::default();
@@ -270,4 +786,142 @@ mod tests {
         assert_eq!(info.name, "test_model");
         assert!(info.version >= 1);
     }
+
+    #[tokio::test]
+    async fn test_api_save_and_load_model_round_trip() {
+        let api = ContinuumApi::default();
+        api.register_model("test_model", "linear", None).await.unwrap();
+
+        for i in 0..5 {
+            api.add_training_example("test_model", vec![i as f32], (i * 2) as f32, false).await.unwrap();
+        }
+        api.train_model("test_model").await.unwrap();
+        let before = api.predict("test_model", vec![5.0]).await.unwrap();
+
+        let path = std::env::temp_dir().join(format!("continuum_api_snapshot_test_{}.json", std::process::id()));
+        api.save_model("test_model", path.to_str().unwrap(), "json").await.unwrap();
+
+        api.register_model("restored_model", "linear", None).await.unwrap();
+        api.load_model("restored_model", path.to_str().unwrap()).await.unwrap();
+        let after = api.predict("restored_model", vec![5.0]).await.unwrap();
+
+        assert_eq!(before.prediction, after.prediction);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_api_save_model_rejects_unknown_format() {
+        let api = ContinuumApi::default();
+        api.register_model("test_model", "linear", None).await.unwrap();
+
+        let result = api.save_model("test_model", "/tmp/irrelevant", "protobuf").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_rollback_model() {
+        let config = ContinuousLearningConfig::default();
+        let api = ContinuumApi::new(config);
+        api.register_model("test_model", "linear", None).await.unwrap();
+
+        for i in 0..5 {
+            api.add_training_example("test_model", vec![i as f32], (i * 2) as f32, false).await.unwrap();
+        }
+        api.train_model("test_model").await.unwrap();
+        let info = api.get_model_info("test_model").await.unwrap();
+
+        let version = api.rollback_model("test_model", info.version).await.unwrap();
+        assert_eq!(version, info.version);
+    }
+
+    #[tokio::test]
+    async fn test_api_logistic_classifier_train_and_classify() {
+        let api = ContinuumApi::default();
+
+        let params = ModelParameters {
+            classes: Some(vec!["neg".to_string(), "pos".to_string()]),
+            ..Default::default()
+        };
+        api.register_model("test_classifier", "logistic", Some(params)).await.unwrap();
+
+        api.train_classifier(
+            "test_classifier",
+            vec![vec![-2.0], vec![-1.0], vec![1.0], vec![2.0]],
+            vec![0, 0, 1, 1],
+        )
+        .await
+        .unwrap();
+
+        let response = api.classify("test_classifier", vec![3.0]).await.unwrap();
+        assert_eq!(response.labels, vec!["neg".to_string(), "pos".to_string()]);
+        assert!(response.scores[1] > response.scores[0]);
+        assert_eq!(response.model_version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_svm_classifier_batch_classify() {
+        let api = ContinuumApi::default();
+
+        let params = ModelParameters {
+            classes: Some(vec!["neg".to_string(), "pos".to_string()]),
+            ..Default::default()
+        };
+        api.register_model("test_svm", "svm", Some(params)).await.unwrap();
+
+        api.train_classifier(
+            "test_svm",
+            vec![vec![-2.0], vec![-1.0], vec![1.0], vec![2.0]],
+            vec![0, 0, 1, 1],
+        )
+        .await
+        .unwrap();
+
+        let response = api.classify_batch("test_svm", vec![vec![-3.0], vec![3.0]]).await.unwrap();
+        assert_eq!(response.scores.len(), 2);
+        assert!(response.scores[0][0] > response.scores[0][1]);
+        assert!(response.scores[1][1] > response.scores[1][0]);
+    }
+
+    #[tokio::test]
+    async fn test_api_register_classifier_rejects_missing_classes() {
+        let api = ContinuumApi::default();
+        let result = api.register_model("test_classifier", "logistic", None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_anomaly_detector_flags_outlier() {
+        let api = ContinuumApi::default();
+
+        let params = ModelParameters { z_threshold: Some(3.0), window_size: Some(20), ..Default::default() };
+        api.register_model("test_detector", "anomaly", Some(params)).await.unwrap();
+
+        for _ in 0..30 {
+            api.add_training_example("test_detector", vec![10.0], 0.0, false).await.unwrap();
+        }
+        api.train_model("test_detector").await.unwrap();
+
+        let response = api.detect_anomaly("test_detector", vec![10.0]).await.unwrap();
+        assert!(!response.is_anomaly, "a point matching the running mean shouldn't be anomalous, got score {}", response.score);
+
+        let response = api.detect_anomaly("test_detector", vec![1000.0]).await.unwrap();
+        assert!(response.is_anomaly, "a wildly out-of-range point should be anomalous, got score {}", response.score);
+        assert_eq!(response.model_version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_metrics_snapshot_counts_calls_and_failures() {
+        let api = ContinuumApi::default();
+
+        api.register_model("test_model", "linear", None).await.unwrap();
+        api.predict("test_model", vec![1.0]).await.unwrap();
+        let _ = api.predict("missing_model", vec![1.0]).await;
+
+        let snapshot = api.metrics_snapshot().await;
+        assert!(snapshot.contains("continuum_api_requests_total 3"));
+        assert!(snapshot.contains("continuum_api_request_failures_total 1"));
+        assert!(snapshot.contains("continuum_api_requests_by_model_total{model=\"test_model\"} 2"));
+        // Per-model stats from the underlying ModelServer are folded in too
+        assert!(snapshot.contains("continuum_model_version{model=\"test_model\"} 1"));
+    }
 }
\ No newline at end of file
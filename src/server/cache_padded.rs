@@ -0,0 +1,74 @@
+//! Cache-line padding for hot, independently-updated atomics
+//!
+//! Under concurrent access, atomics that share a cache line suffer false sharing:
+//! an update to one forces every core caching that line to reload the others, even
+//! though they're logically unrelated. `CachePadded<T>` pads and aligns `T` to a
+//! full cache line so it never shares one with a neighboring field, mirroring the
+//! shape of `crossbeam_utils::CachePadded` without pulling in the dependency.
+
+use std::fmt;
+use std::ops::Deref;
+
+// Most modern x86_64/aarch64/powerpc64 cores use 64-byte cache lines, but some
+// (recent Intel desktop/server parts with adjacent-line prefetch, Apple Silicon's
+// M-series) effectively behave like 128-byte lines, so pad to 128 there; 64 bytes
+// elsewhere. This mirrors crossbeam_utils::CachePadded's arch table.
+#[cfg_attr(
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64"),
+    repr(align(128))
+)]
+#[cfg_attr(
+    not(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64")),
+    repr(align(64))
+)]
+#[derive(Default)]
+pub struct CachePadded<T> {
+    value: T,
+}
+
+impl<T> CachePadded<T> {
+    /// Pad `value` out to its own cache line
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CachePadded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachePadded").field("value", &self.value).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_cache_padded_is_aligned_to_a_full_cache_line() {
+        let expected = if cfg!(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64")) {
+            128
+        } else {
+            64
+        };
+
+        assert_eq!(align_of::<CachePadded<AtomicUsize>>(), expected);
+        assert!(size_of::<CachePadded<AtomicUsize>>() >= expected);
+    }
+
+    #[test]
+    fn test_cache_padded_derefs_to_the_wrapped_value() {
+        let padded = CachePadded::new(AtomicUsize::new(0));
+        padded.fetch_add(5, Ordering::SeqCst);
+        assert_eq!(padded.load(Ordering::SeqCst), 5);
+    }
+}
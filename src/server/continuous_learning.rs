@@ -1,5 +1,21 @@
+use std::path::PathBuf;
 use std::time::SystemTime;
+use rand::Rng;
 use crate::traits::features::FeatureVector;
+use crate::traits::model::SerializationFormat;
+use crate::server::tuner::HyperparamSpace;
+
+/// What to do when a model's live prediction distribution drifts too far from its
+/// reference distribution (see `crate::server::drift::DriftMonitor`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftPolicy {
+    /// Drift is only observable via `ModelServer`/metrics; no corrective action is taken
+    Ignore,
+    /// Mark the affected model "stale" so operators and metrics can surface the condition
+    MarkStale,
+    /// Force an immediate retrain cycle for the affected model, bypassing `min_samples`
+    ForceRetrain,
+}
 
 /// Configuration for continuous learning
 #[derive(Debug, Clone)]
@@ -16,6 +32,50 @@ pub struct ContinuousLearningConfig {
     pub validation_threshold: f32,
     /// Whether to use KL divergence for swap decisions
     pub use_kl_divergence: bool,
+    /// Minimum KL divergence required between the incumbent's and a candidate's
+    /// predictive distributions for the candidate to be swapped in; only consulted
+    /// when `use_kl_divergence` is true
+    pub min_kl_divergence: f32,
+    /// Maximum number of requests the prediction dispatcher accumulates before
+    /// flushing a batch to the model, even if `max_batch_delay_us` hasn't elapsed
+    pub max_batch_size: usize,
+    /// Maximum time (in microseconds) the prediction dispatcher waits after the first
+    /// queued request before flushing a partial batch
+    pub max_batch_delay_us: u64,
+    /// Maximum number of previously-swapped-in model versions retained for rollback
+    pub max_version_history: usize,
+    /// Number of recent prediction outputs kept in the drift monitor's sliding window
+    pub drift_window_size: usize,
+    /// Number of equal-width histogram bins used to compare prediction distributions
+    pub drift_bins: usize,
+    /// KL divergence above which the live prediction distribution is considered drifted
+    pub drift_threshold: f32,
+    /// What to do when drift exceeds `drift_threshold`
+    pub drift_policy: DriftPolicy,
+    /// Declared hyperparameter search space for Bayesian-optimization tuning;
+    /// `None` disables tuning regardless of how a model was registered
+    pub tuning_space: Option<HyperparamSpace>,
+    /// Number of `train_now` cycles between tuning trials for a model tuned
+    /// via `ModelServer::register_model_with_tuning`
+    pub tuning_cadence: usize,
+    /// Number of most-recent `train_now` cycle reports retained per model (see
+    /// `crate::server::training_history::TrainingHistory`)
+    pub training_history_capacity: usize,
+    /// Exponential decay rate `λ` applied to a sample's importance weight based on
+    /// its age (`w = exp(-λ · Δt)`, `Δt` in seconds since insertion); `0.0` disables
+    /// decay so every sample keeps full weight regardless of age
+    pub recency_decay_rate: f32,
+    /// Probability that a sample added via `TrainingBuffer::add_auto` is routed to
+    /// validation rather than training; `0.0` routes every auto-assigned sample to
+    /// training
+    pub validation_assign_probability: f32,
+    /// Directory under which each model gets its own subdirectory of versioned
+    /// rollback snapshots (see `crate::server::snapshot::SnapshotStore`); `None`
+    /// (the default) disables disk persistence, so rollback is limited to the
+    /// `max_version_history` in-memory versions, which don't survive a restart
+    pub snapshot_dir: Option<PathBuf>,
+    /// Format used to serialize on-disk snapshots when `snapshot_dir` is set
+    pub snapshot_format: SerializationFormat,
 }
 
 impl Default for ContinuousLearningConfig {
@@ -27,10 +87,69 @@ impl Default for ContinuousLearningConfig {
             auto_swap: true,
             validation_threshold: 0.05, // 5% improvement required
             use_kl_divergence: false,
+            min_kl_divergence: default_min_kl_divergence(),
+            max_batch_size: default_max_batch_size(),
+            max_batch_delay_us: default_max_batch_delay_us(),
+            max_version_history: default_max_version_history(),
+            drift_window_size: default_drift_window_size(),
+            drift_bins: default_drift_bins(),
+            drift_threshold: default_drift_threshold(),
+            drift_policy: DriftPolicy::Ignore,
+            tuning_space: None,
+            tuning_cadence: default_tuning_cadence(),
+            training_history_capacity: default_training_history_capacity(),
+            recency_decay_rate: default_recency_decay_rate(),
+            validation_assign_probability: default_validation_assign_probability(),
+            snapshot_dir: None,
+            snapshot_format: SerializationFormat::Json,
         }
     }
 }
 
+fn default_max_batch_size() -> usize {
+    32
+}
+
+fn default_max_batch_delay_us() -> u64 {
+    5_000 // 5ms
+}
+
+fn default_max_version_history() -> usize {
+    5
+}
+
+fn default_drift_window_size() -> usize {
+    200
+}
+
+fn default_drift_bins() -> usize {
+    10
+}
+
+fn default_drift_threshold() -> f32 {
+    0.5
+}
+
+fn default_tuning_cadence() -> usize {
+    5
+}
+
+fn default_min_kl_divergence() -> f32 {
+    0.01
+}
+
+fn default_training_history_capacity() -> usize {
+    50
+}
+
+fn default_recency_decay_rate() -> f32 {
+    0.0
+}
+
+fn default_validation_assign_probability() -> f32 {
+    0.0
+}
+
 impl ContinuousLearningConfig {
     /// Create a new configuration with custom values
     pub fn new(
@@ -48,9 +167,24 @@ impl ContinuousLearningConfig {
             auto_swap,
             validation_threshold,
             use_kl_divergence,
+            min_kl_divergence: default_min_kl_divergence(),
+            max_batch_size: default_max_batch_size(),
+            max_batch_delay_us: default_max_batch_delay_us(),
+            max_version_history: default_max_version_history(),
+            drift_window_size: default_drift_window_size(),
+            drift_bins: default_drift_bins(),
+            drift_threshold: default_drift_threshold(),
+            drift_policy: DriftPolicy::Ignore,
+            tuning_space: None,
+            tuning_cadence: default_tuning_cadence(),
+            training_history_capacity: default_training_history_capacity(),
+            recency_decay_rate: default_recency_decay_rate(),
+            validation_assign_probability: default_validation_assign_probability(),
+            snapshot_dir: None,
+            snapshot_format: SerializationFormat::Json,
         }
     }
-    
+
     /// Create a disabled configuration (useful for testing)
     pub fn disabled() -> Self {
         Self {
@@ -58,7 +192,81 @@ impl ContinuousLearningConfig {
             ..Default::default()
         }
     }
-    
+
+    /// Configure the prediction micro-batching dispatcher's size/latency triggers
+    pub fn with_batching(mut self, max_batch_size: usize, max_batch_delay_us: u64) -> Self {
+        self.max_batch_size = max_batch_size;
+        self.max_batch_delay_us = max_batch_delay_us;
+        self
+    }
+
+    /// Configure how many previously-swapped-in model versions are retained for rollback
+    pub fn with_max_version_history(mut self, max_version_history: usize) -> Self {
+        self.max_version_history = max_version_history.max(1);
+        self
+    }
+
+    /// Configure concept-drift monitoring: sliding-window size, histogram bin count,
+    /// the KL-divergence threshold considered "drifted", and the policy to apply
+    pub fn with_drift_detection(
+        mut self,
+        window_size: usize,
+        bins: usize,
+        threshold: f32,
+        policy: DriftPolicy,
+    ) -> Self {
+        self.drift_window_size = window_size.max(1);
+        self.drift_bins = bins.max(1);
+        self.drift_threshold = threshold;
+        self.drift_policy = policy;
+        self
+    }
+
+    /// Enable KL-divergence-gated swap decisions: a candidate model is only promoted
+    /// once it both clears `validation_threshold` on raw error and its predictive
+    /// distribution has diverged from the incumbent's by at least `min_kl_divergence`
+    /// (see `crate::server::swap_decision`)
+    pub fn with_kl_divergence_gating(mut self, min_kl_divergence: f32) -> Self {
+        self.use_kl_divergence = true;
+        self.min_kl_divergence = min_kl_divergence;
+        self
+    }
+
+    /// Enable Bayesian-optimization hyperparameter tuning over `space`, attempting
+    /// one trial candidate every `cadence` training cycles for models registered via
+    /// `ModelServer::register_model_with_tuning`
+    pub fn with_tuning(mut self, space: HyperparamSpace, cadence: usize) -> Self {
+        self.tuning_space = Some(space);
+        self.tuning_cadence = cadence.max(1);
+        self
+    }
+
+    /// Configure how many recent `train_now` cycle reports are retained per model
+    pub fn with_training_history_capacity(mut self, capacity: usize) -> Self {
+        self.training_history_capacity = capacity.max(1);
+        self
+    }
+
+    /// Enable recency-weighted sample importance: training and validation samples
+    /// decay toward zero weight as they age (`decay_rate` is `λ` in `exp(-λ · Δt)`),
+    /// and samples added via `TrainingBuffer::add_auto` are routed to validation with
+    /// probability `validation_assign_probability` instead of the caller deciding
+    pub fn with_recency_weighting(mut self, decay_rate: f32, validation_assign_probability: f32) -> Self {
+        self.recency_decay_rate = decay_rate.max(0.0);
+        self.validation_assign_probability = validation_assign_probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enable disk-backed snapshot persistence: each registered model gets its own
+    /// subdirectory of `dir` holding its versioned rollback snapshots, serialized in
+    /// `format`, so `ModelServer::rollback` can recover a retired version after a
+    /// process restart (see `crate::server::snapshot::SnapshotStore`)
+    pub fn with_snapshot_dir(mut self, dir: impl Into<PathBuf>, format: SerializationFormat) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self.snapshot_format = format;
+        self
+    }
+
     /// Create a configuration optimized for frequent updates
     pub fn frequent_updates() -> Self {
         Self {
@@ -70,6 +278,23 @@ impl ContinuousLearningConfig {
     }
 }
 
+/// Eviction policy applied once `features`/`val_features` reach `max_size`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Drop the oldest sample, keeping only the most recent `max_size` window.
+    /// O(n) per insert (shifts the vector) and biased toward recent arrivals.
+    DropOldest,
+    /// Classic reservoir sampling (Algorithm R): every sample seen so far is
+    /// retained with uniform probability `max_size / samples_seen`, in O(1) per
+    /// insert, without storing the full stream or favoring recent arrivals
+    ReservoirSample,
+    /// Weighted reservoir sampling (Efraimidis-Spirakis `A-ES`): each sample's
+    /// per-sample weight biases its retention probability, so higher-weighted
+    /// samples (e.g. more recent or more confident) are proportionally more
+    /// likely to survive eviction
+    ReservoirWeighted,
+}
+
 /// Buffer for accumulating training data
 #[derive(Debug)]
 pub struct TrainingBuffer {
@@ -77,14 +302,39 @@ pub struct TrainingBuffer {
     pub features: Vec<FeatureVector>,
     /// Target values for training
     pub targets: Vec<f32>,
+    /// Per-sample training weights, parallel to `features`/`targets` (defaults to 1.0)
+    pub weights: Vec<f32>,
     /// Validation feature vectors
     pub val_features: Vec<FeatureVector>,
     /// Validation target values
     pub val_targets: Vec<f32>,
     /// Last time the buffer was trained
     pub last_trained: SystemTime,
-    /// Maximum buffer size (after which oldest entries are dropped)
+    /// Maximum buffer size (after which `eviction_policy` decides what's retained)
     pub max_size: Option<usize>,
+    /// Eviction policy applied once `max_size` is reached
+    pub eviction_policy: EvictionPolicy,
+    /// Total training samples offered since the buffer (or the last `clear_training`)
+    /// started accumulating; drives `ReservoirSample`/`ReservoirWeighted` inclusion odds
+    train_samples_seen: usize,
+    /// Total validation samples offered since the buffer (or the last `clear_validation`)
+    /// started accumulating
+    val_samples_seen: usize,
+    /// Efraimidis-Spirakis priority key retained per training slot, parallel to
+    /// `features`/`targets`/`weights`; only populated under `ReservoirWeighted`
+    reservoir_keys: Vec<f32>,
+    /// Insertion time of each training sample, parallel to `features`/`targets`/`weights`;
+    /// drives the recency-decayed weight returned by `get_training_data_weighted`
+    timestamps: Vec<SystemTime>,
+    /// Insertion time of each validation sample, parallel to `val_features`/`val_targets`;
+    /// drives the recency-decayed weight returned by `get_validation_data_weighted`
+    val_timestamps: Vec<SystemTime>,
+    /// Exponential decay rate `λ` applied to sample age when computing recency weight;
+    /// `0.0` disables decay (see `ContinuousLearningConfig::recency_decay_rate`)
+    decay_rate: f32,
+    /// Probability that `add_auto` routes an incoming sample to validation
+    /// (see `ContinuousLearningConfig::validation_assign_probability`)
+    validation_probability: f32,
 }
 
 impl TrainingBuffer {
@@ -93,68 +343,203 @@ impl TrainingBuffer {
         Self {
             features: Vec::new(),
             targets: Vec::new(),
+            weights: Vec::new(),
             val_features: Vec::new(),
             val_targets: Vec::new(),
             last_trained: SystemTime::now(),
             max_size: None,
+            eviction_policy: EvictionPolicy::DropOldest,
+            train_samples_seen: 0,
+            val_samples_seen: 0,
+            reservoir_keys: Vec::new(),
+            timestamps: Vec::new(),
+            val_timestamps: Vec::new(),
+            decay_rate: 0.0,
+            validation_probability: 0.0,
         }
     }
-    
+
     /// Create a new training buffer with a maximum size
     pub fn with_max_size(max_size: usize) -> Self {
         Self {
-            features: Vec::new(),
-            targets: Vec::new(),
-            val_features: Vec::new(),
-            val_targets: Vec::new(),
-            last_trained: SystemTime::now(),
             max_size: Some(max_size),
+            ..Self::new()
         }
     }
-    
-    /// Add a new training example
+
+    /// Select the eviction policy applied once the buffer reaches `max_size`
+    pub fn with_eviction_policy(mut self, policy: EvictionPolicy) -> Self {
+        self.eviction_policy = policy;
+        self
+    }
+
+    /// Enable recency-weighted sample importance: see
+    /// `ContinuousLearningConfig::with_recency_weighting`
+    pub fn with_recency_weighting(mut self, decay_rate: f32, validation_probability: f32) -> Self {
+        self.decay_rate = decay_rate.max(0.0);
+        self.validation_probability = validation_probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Add a new training example (weighted at 1.0)
     pub fn add(&mut self, feature: FeatureVector, target: f32, is_validation: bool) {
+        self.add_weighted(feature, target, 1.0, is_validation)
+    }
+
+    /// Add a new training example with an explicit per-sample weight
+    ///
+    /// Weights only apply to training samples; validation samples are unweighted,
+    /// matching `add`.
+    pub fn add_weighted(&mut self, feature: FeatureVector, target: f32, weight: f32, is_validation: bool) {
         if is_validation {
-            self.val_features.push(feature);
-            self.val_targets.push(target);
-            
-            // Enforce max size for validation data
-            if let Some(max_size) = self.max_size {
+            self.val_samples_seen += 1;
+            self.insert_validation(feature, target);
+        } else {
+            self.train_samples_seen += 1;
+            self.insert_training(feature, target, weight);
+        }
+    }
+
+    /// Add a new training example, auto-assigning it to train or validation by
+    /// `validation_probability` instead of requiring the caller to decide
+    ///
+    /// Configure the assignment probability via `with_recency_weighting`; a buffer
+    /// that hasn't opted in always routes auto-assigned samples to training.
+    pub fn add_auto(&mut self, feature: FeatureVector, target: f32, weight: f32) {
+        let is_validation = rand::thread_rng().gen_range(0.0..1.0) < self.validation_probability;
+        self.add_weighted(feature, target, weight, is_validation)
+    }
+
+    fn insert_validation(&mut self, feature: FeatureVector, target: f32) {
+        let now = SystemTime::now();
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => {
+                self.val_features.push(feature);
+                self.val_targets.push(target);
+                self.val_timestamps.push(now);
+                return;
+            }
+        };
+
+        match self.eviction_policy {
+            EvictionPolicy::DropOldest => {
+                self.val_features.push(feature);
+                self.val_targets.push(target);
+                self.val_timestamps.push(now);
                 if self.val_features.len() > max_size {
                     self.val_features.remove(0);
                     self.val_targets.remove(0);
+                    self.val_timestamps.remove(0);
                 }
             }
-        } else {
-            self.features.push(feature);
-            self.targets.push(target);
-            
-            // Enforce max size for training data
-            if let Some(max_size) = self.max_size {
+            // Validation samples carry no explicit per-sample weight, so reservoir
+            // sampling over them is plain unweighted Algorithm R under both policies
+            EvictionPolicy::ReservoirSample | EvictionPolicy::ReservoirWeighted => {
+                if self.val_features.len() < max_size {
+                    self.val_features.push(feature);
+                    self.val_targets.push(target);
+                    self.val_timestamps.push(now);
+                } else if let Some(slot) = reservoir_slot(self.val_samples_seen, max_size) {
+                    self.val_features[slot] = feature;
+                    self.val_targets[slot] = target;
+                    self.val_timestamps[slot] = now;
+                }
+            }
+        }
+    }
+
+    fn insert_training(&mut self, feature: FeatureVector, target: f32, weight: f32) {
+        let now = SystemTime::now();
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => {
+                self.features.push(feature);
+                self.targets.push(target);
+                self.weights.push(weight);
+                self.timestamps.push(now);
+                return;
+            }
+        };
+
+        match self.eviction_policy {
+            EvictionPolicy::DropOldest => {
+                self.features.push(feature);
+                self.targets.push(target);
+                self.weights.push(weight);
+                self.timestamps.push(now);
                 if self.features.len() > max_size {
                     self.features.remove(0);
                     self.targets.remove(0);
+                    self.weights.remove(0);
+                    self.timestamps.remove(0);
+                }
+            }
+            EvictionPolicy::ReservoirSample => {
+                if self.features.len() < max_size {
+                    self.features.push(feature);
+                    self.targets.push(target);
+                    self.weights.push(weight);
+                    self.timestamps.push(now);
+                } else if let Some(slot) = reservoir_slot(self.train_samples_seen, max_size) {
+                    self.features[slot] = feature;
+                    self.targets[slot] = target;
+                    self.weights[slot] = weight;
+                    self.timestamps[slot] = now;
+                }
+            }
+            EvictionPolicy::ReservoirWeighted => {
+                let key = reservoir_priority_key(weight);
+                if self.features.len() < max_size {
+                    self.features.push(feature);
+                    self.targets.push(target);
+                    self.weights.push(weight);
+                    self.timestamps.push(now);
+                    self.reservoir_keys.push(key);
+                } else {
+                    let weakest = self
+                        .reservoir_keys
+                        .iter()
+                        .enumerate()
+                        .min_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+                        .map(|(idx, &min_key)| (idx, min_key));
+
+                    if let Some((slot, min_key)) = weakest {
+                        if key > min_key {
+                            self.features[slot] = feature;
+                            self.targets[slot] = target;
+                            self.weights[slot] = weight;
+                            self.timestamps[slot] = now;
+                            self.reservoir_keys[slot] = key;
+                        }
+                    }
                 }
             }
         }
     }
-    
+
     /// Check if buffer has enough samples for training
     pub fn has_min_samples(&self, min_samples: usize) -> bool {
         self.features.len() >= min_samples
     }
-    
+
     /// Clear training data (but keep validation data)
     pub fn clear_training(&mut self) {
         self.features.clear();
         self.targets.clear();
+        self.weights.clear();
+        self.reservoir_keys.clear();
+        self.timestamps.clear();
+        self.train_samples_seen = 0;
         self.last_trained = SystemTime::now();
     }
-    
+
     /// Clear validation data
     pub fn clear_validation(&mut self) {
         self.val_features.clear();
         self.val_targets.clear();
+        self.val_timestamps.clear();
+        self.val_samples_seen = 0;
     }
     
     /// Clear all data
@@ -177,11 +562,40 @@ impl TrainingBuffer {
     pub fn get_training_data(&self) -> (&[FeatureVector], &[f32]) {
         (&self.features, &self.targets)
     }
-    
+
+    /// Get training data along with its per-sample weight: the explicit weight set
+    /// via `add_weighted`, scaled by the recency-decay factor for that sample's age
+    pub fn get_training_data_weighted(&self) -> (&[FeatureVector], &[f32], Vec<f32>) {
+        let weights = self
+            .weights
+            .iter()
+            .zip(&self.timestamps)
+            .map(|(&weight, &timestamp)| weight * self.decay_factor(timestamp))
+            .collect();
+        (&self.features, &self.targets, weights)
+    }
+
     /// Get validation data as references
     pub fn get_validation_data(&self) -> (&[FeatureVector], &[f32]) {
         (&self.val_features, &self.val_targets)
     }
+
+    /// Get validation data along with each sample's recency-decay weight, so a
+    /// validation-threshold check can emphasize recent behavior over stale data
+    pub fn get_validation_data_weighted(&self) -> (&[FeatureVector], &[f32], Vec<f32>) {
+        let weights = self.val_timestamps.iter().map(|&timestamp| self.decay_factor(timestamp)).collect();
+        (&self.val_features, &self.val_targets, weights)
+    }
+
+    /// Recency-decay factor `exp(-λ · Δt)` for a sample inserted at `timestamp`,
+    /// `Δt` measured against now in seconds; always `1.0` when decay is disabled
+    fn decay_factor(&self, timestamp: SystemTime) -> f32 {
+        if self.decay_rate <= 0.0 {
+            return 1.0;
+        }
+        let age_secs = SystemTime::now().duration_since(timestamp).unwrap_or_default().as_secs_f32();
+        (-self.decay_rate * age_secs).exp()
+    }
 }
 
 impl Default for TrainingBuffer {
@@ -190,6 +604,27 @@ impl Default for TrainingBuffer {
     }
 }
 
+/// Classic reservoir sampling (Algorithm R) slot decision for the `samples_seen`-th
+/// arrival (1-indexed) into a reservoir of capacity `max_size` that's already full:
+/// draw `j` uniformly from `0..=i` (`i = samples_seen - 1`, 0-indexed) and replace
+/// slot `j` if it falls within the reservoir, else discard the new item
+fn reservoir_slot(samples_seen: usize, max_size: usize) -> Option<usize> {
+    let i = samples_seen.saturating_sub(1);
+    let j = rand::thread_rng().gen_range(0..=i);
+    if j < max_size {
+        Some(j)
+    } else {
+        None
+    }
+}
+
+/// Efraimidis-Spirakis priority key `u^(1/weight)` for weighted reservoir sampling;
+/// the reservoir retains the `max_size` items with the largest keys
+fn reservoir_priority_key(weight: f32) -> f32 {
+    let u: f32 = rand::thread_rng().gen_range(f32::EPSILON..1.0);
+    u.powf(1.0 / weight.max(f32::EPSILON))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +659,85 @@ mod tests {
         assert!(config.use_kl_divergence);
     }
     
+    #[test]
+    fn test_continuous_learning_config_with_batching() {
+        let config = ContinuousLearningConfig::default().with_batching(64, 2_000);
+        assert_eq!(config.max_batch_size, 64);
+        assert_eq!(config.max_batch_delay_us, 2_000);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_max_version_history() {
+        let config = ContinuousLearningConfig::default().with_max_version_history(10);
+        assert_eq!(config.max_version_history, 10);
+
+        // Zero is clamped up to 1 so there's always at least the current version retained
+        let config = ContinuousLearningConfig::default().with_max_version_history(0);
+        assert_eq!(config.max_version_history, 1);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_drift_detection() {
+        let config = ContinuousLearningConfig::default()
+            .with_drift_detection(500, 20, 0.25, DriftPolicy::ForceRetrain);
+        assert_eq!(config.drift_window_size, 500);
+        assert_eq!(config.drift_bins, 20);
+        assert_eq!(config.drift_threshold, 0.25);
+        assert_eq!(config.drift_policy, DriftPolicy::ForceRetrain);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_tuning() {
+        use crate::server::tuner::HyperparamRange;
+
+        let space = HyperparamSpace::new(vec![HyperparamRange::new(0.001, 0.1)]);
+        let config = ContinuousLearningConfig::default().with_tuning(space, 0);
+
+        assert!(config.tuning_space.is_some());
+        // Zero is clamped up to 1 so a trial is still attempted every cycle
+        assert_eq!(config.tuning_cadence, 1);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_kl_divergence_gating() {
+        let config = ContinuousLearningConfig::default().with_kl_divergence_gating(0.2);
+        assert!(config.use_kl_divergence);
+        assert_eq!(config.min_kl_divergence, 0.2);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_training_history_capacity() {
+        let config = ContinuousLearningConfig::default().with_training_history_capacity(10);
+        assert_eq!(config.training_history_capacity, 10);
+
+        // Zero is clamped up to 1 so there's always at least the most recent cycle retained
+        let config = ContinuousLearningConfig::default().with_training_history_capacity(0);
+        assert_eq!(config.training_history_capacity, 1);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_recency_weighting() {
+        let config = ContinuousLearningConfig::default().with_recency_weighting(0.1, 0.2);
+        assert_eq!(config.recency_decay_rate, 0.1);
+        assert_eq!(config.validation_assign_probability, 0.2);
+
+        // Out-of-range validation probability is clamped into [0.0, 1.0]
+        let config = ContinuousLearningConfig::default().with_recency_weighting(-1.0, 1.5);
+        assert_eq!(config.recency_decay_rate, 0.0);
+        assert_eq!(config.validation_assign_probability, 1.0);
+    }
+
+    #[test]
+    fn test_continuous_learning_config_with_snapshot_dir() {
+        let config = ContinuousLearningConfig::default()
+            .with_snapshot_dir("/tmp/continuum-snapshots", SerializationFormat::Json);
+        assert_eq!(config.snapshot_dir, Some(PathBuf::from("/tmp/continuum-snapshots")));
+        assert_eq!(config.snapshot_format, SerializationFormat::Json);
+
+        let config = ContinuousLearningConfig::default();
+        assert!(config.snapshot_dir.is_none(), "disk persistence is opt-in");
+    }
+
     #[test]
     fn test_continuous_learning_config_disabled() {
         let config = ContinuousLearningConfig::disabled();
@@ -366,4 +880,146 @@ mod tests {
         assert_eq!(val_targets.len(), 1);
         assert_eq!(val_targets[0], 20.0);
     }
+
+    #[test]
+    fn test_training_buffer_weighted_add() {
+        let mut buffer = TrainingBuffer::new();
+        let feature = FeatureVector::new(vec![1.0]);
+
+        // Plain `add` defaults to weight 1.0
+        buffer.add(feature.clone(), 1.0, false);
+        // Explicit weight
+        buffer.add_weighted(feature.clone(), 2.0, 0.5, false);
+
+        let (features, targets, weights) = buffer.get_training_data_weighted();
+        assert_eq!(features.len(), 2);
+        assert_eq!(targets, &[1.0, 2.0]);
+        assert_eq!(weights, &[1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_training_buffer_reservoir_sample_never_exceeds_max_size() {
+        let mut buffer = TrainingBuffer::with_max_size(3).with_eviction_policy(EvictionPolicy::ReservoirSample);
+
+        for i in 0..100 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            buffer.add(feature, i as f32, false);
+        }
+
+        assert_eq!(buffer.features.len(), 3);
+        assert_eq!(buffer.targets.len(), 3);
+    }
+
+    #[test]
+    fn test_training_buffer_reservoir_sample_keeps_first_k_until_full() {
+        let mut buffer = TrainingBuffer::with_max_size(3).with_eviction_policy(EvictionPolicy::ReservoirSample);
+
+        for i in 0..3 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            buffer.add(feature, i as f32, false);
+        }
+
+        // Below capacity: every arrival is retained, in order
+        assert_eq!(buffer.targets, vec![0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_training_buffer_reservoir_weighted_never_exceeds_max_size() {
+        let mut buffer = TrainingBuffer::with_max_size(4).with_eviction_policy(EvictionPolicy::ReservoirWeighted);
+
+        for i in 0..50 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            buffer.add_weighted(feature, i as f32, (i % 5 + 1) as f32, false);
+        }
+
+        assert_eq!(buffer.features.len(), 4);
+        assert_eq!(buffer.targets.len(), 4);
+        assert_eq!(buffer.weights.len(), 4);
+    }
+
+    #[test]
+    fn test_training_buffer_reservoir_weighted_favors_higher_weight_samples() {
+        // A single very-high-weight sample dropped into an otherwise low-weight stream
+        // should almost always survive subsequent arrivals
+        let mut buffer = TrainingBuffer::with_max_size(2).with_eviction_policy(EvictionPolicy::ReservoirWeighted);
+
+        buffer.add_weighted(FeatureVector::new(vec![0.0]), 0.0, 1e6, false);
+        buffer.add_weighted(FeatureVector::new(vec![1.0]), 1.0, 0.01, false);
+
+        for i in 2..200 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            buffer.add_weighted(feature, i as f32, 0.01, false);
+        }
+
+        assert!(buffer.targets.contains(&0.0), "the high-weight sample should have survived eviction");
+    }
+
+    #[test]
+    fn test_training_buffer_add_auto_routes_by_validation_probability() {
+        let mut always_train = TrainingBuffer::new().with_recency_weighting(0.0, 0.0);
+        for i in 0..5 {
+            always_train.add_auto(FeatureVector::new(vec![i as f32]), i as f32, 1.0);
+        }
+        assert_eq!(always_train.get_sizes(), (5, 0));
+
+        let mut always_val = TrainingBuffer::new().with_recency_weighting(0.0, 1.0);
+        for i in 0..5 {
+            always_val.add_auto(FeatureVector::new(vec![i as f32]), i as f32, 1.0);
+        }
+        assert_eq!(always_val.get_sizes(), (0, 5));
+    }
+
+    #[test]
+    fn test_training_buffer_recency_weighting_decays_older_samples() {
+        let mut buffer = TrainingBuffer::new().with_recency_weighting(1.0, 0.0);
+
+        buffer.add(FeatureVector::new(vec![0.0]), 0.0, false);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        buffer.add(FeatureVector::new(vec![1.0]), 1.0, false);
+
+        let (_, _, weights) = buffer.get_training_data_weighted();
+        assert!(weights[0] < weights[1], "the older sample should carry strictly less weight");
+    }
+
+    #[test]
+    fn test_training_buffer_recency_weighting_disabled_keeps_full_weight() {
+        let mut buffer = TrainingBuffer::new();
+
+        buffer.add(FeatureVector::new(vec![0.0]), 0.0, false);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        buffer.add(FeatureVector::new(vec![1.0]), 1.0, false);
+
+        let (_, _, weights) = buffer.get_training_data_weighted();
+        assert_eq!(weights, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_training_buffer_get_validation_data_weighted() {
+        let mut buffer = TrainingBuffer::new().with_recency_weighting(1.0, 0.0);
+        buffer.add(FeatureVector::new(vec![0.0]), 5.0, true);
+        buffer.add(FeatureVector::new(vec![1.0]), 6.0, true);
+
+        let (val_features, val_targets, val_weights) = buffer.get_validation_data_weighted();
+        assert_eq!(val_features.len(), 2);
+        assert_eq!(val_targets, &[5.0, 6.0]);
+        assert_eq!(val_weights.len(), 2);
+        assert!(val_weights.iter().all(|&w| w > 0.0 && w <= 1.0));
+    }
+
+    #[test]
+    fn test_training_buffer_clear_training_resets_reservoir_state() {
+        let mut buffer = TrainingBuffer::with_max_size(2).with_eviction_policy(EvictionPolicy::ReservoirSample);
+
+        for i in 0..10 {
+            buffer.add(FeatureVector::new(vec![i as f32]), i as f32, false);
+        }
+        buffer.clear_training();
+
+        // A fresh accumulation cycle should behave like an empty reservoir again:
+        // the first `max_size` arrivals are always retained
+        for i in 0..2 {
+            buffer.add(FeatureVector::new(vec![i as f32]), i as f32, false);
+        }
+        assert_eq!(buffer.targets, vec![0.0, 1.0]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,166 @@
+use std::collections::VecDeque;
+
+use crate::server::metrics::kl_divergence;
+
+/// Small constant added to every histogram bin before normalizing, so an empty bin
+/// doesn't zero out (or blow up) the KL divergence against the reference distribution
+const LAPLACE_EPSILON: f32 = 1e-3;
+
+/// Tracks a model's live prediction distribution against a reference snapshot and
+/// reports concept drift via KL divergence.
+///
+/// A sliding window of the last `window_capacity` prediction outputs is binned into
+/// a fixed-edge histogram and compared against a reference histogram captured (via
+/// `snapshot_reference`) from an earlier window, typically right after a model swap.
+#[derive(Debug)]
+pub struct DriftMonitor {
+    /// Sliding window of recent prediction outputs, oldest first
+    window: VecDeque<f32>,
+    /// Maximum number of predictions retained in `window`
+    window_capacity: usize,
+    /// Number of equal-width histogram bins
+    num_bins: usize,
+    /// Reference probability distribution, captured by `snapshot_reference`
+    reference: Option<Vec<f32>>,
+    /// Bin edges (min, max) the reference distribution was computed over
+    reference_range: (f32, f32),
+}
+
+impl DriftMonitor {
+    /// Create a new drift monitor with the given sliding-window capacity and bin count
+    pub fn new(window_capacity: usize, num_bins: usize) -> Self {
+        Self {
+            window: VecDeque::with_capacity(window_capacity.max(1)),
+            window_capacity: window_capacity.max(1),
+            num_bins: num_bins.max(1),
+            reference: None,
+            reference_range: (0.0, 1.0),
+        }
+    }
+
+    /// Record a new prediction output, evicting the oldest once the window is full
+    pub fn record_prediction(&mut self, value: f32) {
+        self.window.push_back(value);
+        while self.window.len() > self.window_capacity {
+            self.window.pop_front();
+        }
+    }
+
+    /// Snapshot the current sliding window as the new reference distribution
+    ///
+    /// No-op if the window is currently empty (e.g. a freshly registered model
+    /// that hasn't served any predictions yet).
+    pub fn snapshot_reference(&mut self) {
+        if self.window.is_empty() {
+            return;
+        }
+
+        let values: Vec<f32> = self.window.iter().copied().collect();
+        let range = Self::value_range(&values);
+        let counts = Self::bin_counts(&values, range, self.num_bins);
+
+        self.reference_range = range;
+        self.reference = Some(Self::smoothed_probabilities(&counts));
+    }
+
+    /// Compute the KL divergence between the current window and the reference
+    /// distribution, or `None` if no reference has been captured yet (or the window
+    /// is currently empty)
+    pub fn compute_drift(&self) -> Option<f32> {
+        let reference = self.reference.as_ref()?;
+        if self.window.is_empty() {
+            return None;
+        }
+
+        let values: Vec<f32> = self.window.iter().copied().collect();
+        let counts = Self::bin_counts(&values, self.reference_range, self.num_bins);
+        let current = Self::smoothed_probabilities(&counts);
+
+        Some(kl_divergence(&current, reference))
+    }
+
+    fn value_range(values: &[f32]) -> (f32, f32) {
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+        for &v in values {
+            min = min.min(v);
+            max = max.max(v);
+        }
+        if min >= max {
+            (min - 0.5, min + 0.5) // degenerate (all-identical) window still yields a usable range
+        } else {
+            (min, max)
+        }
+    }
+
+    fn bin_counts(values: &[f32], (min, max): (f32, f32), num_bins: usize) -> Vec<f32> {
+        let mut counts = vec![0.0f32; num_bins];
+        let range = (max - min).max(f32::EPSILON);
+
+        for &v in values {
+            let clamped = v.clamp(min, max);
+            let idx = (((clamped - min) / range) * num_bins as f32) as usize;
+            counts[idx.min(num_bins - 1)] += 1.0;
+        }
+
+        counts
+    }
+
+    fn smoothed_probabilities(counts: &[f32]) -> Vec<f32> {
+        let smoothed: Vec<f32> = counts.iter().map(|&c| c + LAPLACE_EPSILON).collect();
+        let total: f32 = smoothed.iter().sum();
+        smoothed.into_iter().map(|c| c / total).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_monitor_no_drift_when_distribution_unchanged() {
+        let mut monitor = DriftMonitor::new(100, 10);
+        for i in 0..100 {
+            monitor.record_prediction((i % 10) as f32);
+        }
+        monitor.snapshot_reference();
+
+        // Same distribution replayed into the window should show ~zero divergence
+        let kl = monitor.compute_drift().unwrap();
+        assert!(kl < 1e-3, "expected near-zero KL divergence, got {}", kl);
+    }
+
+    #[test]
+    fn test_drift_monitor_detects_shifted_distribution() {
+        let mut monitor = DriftMonitor::new(100, 10);
+        for i in 0..100 {
+            monitor.record_prediction((i % 10) as f32);
+        }
+        monitor.snapshot_reference();
+
+        // Flood the window with values concentrated at the high end of the reference range
+        for _ in 0..100 {
+            monitor.record_prediction(9.0);
+        }
+
+        let kl = monitor.compute_drift().unwrap();
+        assert!(kl > 0.5, "expected a large KL divergence after distribution shift, got {}", kl);
+    }
+
+    #[test]
+    fn test_drift_monitor_no_reference_yet() {
+        let mut monitor = DriftMonitor::new(10, 5);
+        monitor.record_prediction(1.0);
+        assert!(monitor.compute_drift().is_none());
+    }
+
+    #[test]
+    fn test_drift_monitor_window_capacity_evicts_oldest() {
+        let mut monitor = DriftMonitor::new(3, 5);
+        for i in 0..5 {
+            monitor.record_prediction(i as f32);
+        }
+        assert_eq!(monitor.window.len(), 3);
+        assert_eq!(monitor.window, VecDeque::from(vec![2.0, 3.0, 4.0]));
+    }
+}
@@ -0,0 +1,266 @@
+//! gRPC serving front-end implementing (a subset of) the KServe/Triton v2
+//! `GRPCInferenceService` protocol, gated behind the `grpc-api` feature.
+//!
+//! `ContinuumApi` is otherwise only callable in-process; `ContinuumServer`
+//! wraps one and answers `ServerLive`/`ServerReady`/`ModelReady`/`ModelMetadata`/
+//! `ModelInfer` over the network, so Continuum can be deployed as a standalone
+//! model server rather than a library dependency.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::server::api::{ApiError, ContinuumApi};
+use crate::traits::features::FeatureVector;
+
+pub mod kserve {
+    tonic::include_proto!("inference");
+}
+
+use kserve::grpc_inference_service_server::{GrpcInferenceService, GrpcInferenceServiceServer};
+use kserve::infer_parameter::ParameterChoice;
+use kserve::model_infer_response::InferOutputTensor;
+use kserve::model_metadata_response::TensorMetadata;
+use kserve::{
+    InferParameter, InferTensorContents, ModelInferRequest, ModelInferResponse, ModelMetadataRequest,
+    ModelMetadataResponse, ModelReadyRequest, ModelReadyResponse, ServerLiveRequest, ServerLiveResponse,
+    ServerReadyRequest, ServerReadyResponse,
+};
+
+/// Map an `ApiError` to the `tonic::Status` a gRPC client expects
+fn to_status(err: ApiError) -> Status {
+    match err {
+        ApiError::NotFound(msg) => Status::not_found(msg),
+        ApiError::InvalidInput(msg) => Status::invalid_argument(msg),
+        // `ModelServer` reports an unknown model name as `InvalidParameter`
+        ApiError::ModelError(crate::traits::model::ModelError::InvalidParameter(msg)) => Status::not_found(msg),
+        ApiError::ModelError(err) => Status::internal(err.to_string()),
+    }
+}
+
+/// gRPC front-end over an in-process `ContinuumApi`
+pub struct ContinuumServer {
+    api: Arc<ContinuumApi>,
+}
+
+impl ContinuumServer {
+    /// Wrap an existing `ContinuumApi` for gRPC serving
+    pub fn new(api: Arc<ContinuumApi>) -> Self {
+        Self { api }
+    }
+
+    /// Serve the `GRPCInferenceService` on `addr` until the returned future is
+    /// dropped or the process is terminated
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), tonic::transport::Error> {
+        tonic::transport::Server::builder()
+            .add_service(GrpcInferenceServiceServer::new(self))
+            .serve(addr)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl GrpcInferenceService for ContinuumServer {
+    async fn server_live(
+        &self,
+        _request: Request<ServerLiveRequest>,
+    ) -> Result<Response<ServerLiveResponse>, Status> {
+        Ok(Response::new(ServerLiveResponse { live: true }))
+    }
+
+    async fn server_ready(
+        &self,
+        _request: Request<ServerReadyRequest>,
+    ) -> Result<Response<ServerReadyResponse>, Status> {
+        Ok(Response::new(ServerReadyResponse { ready: true }))
+    }
+
+    async fn model_ready(
+        &self,
+        request: Request<ModelReadyRequest>,
+    ) -> Result<Response<ModelReadyResponse>, Status> {
+        let name = request.into_inner().name;
+        let ready = self.api.get_model_info(&name).await.is_ok();
+        Ok(Response::new(ModelReadyResponse { ready }))
+    }
+
+    async fn model_metadata(
+        &self,
+        request: Request<ModelMetadataRequest>,
+    ) -> Result<Response<ModelMetadataResponse>, Status> {
+        let name = request.into_inner().name;
+        let info = self.api.get_model_info(&name).await.map_err(to_status)?;
+
+        Ok(Response::new(ModelMetadataResponse {
+            name: info.name,
+            versions: vec![info.version.to_string()],
+            platform: "continuum".to_string(),
+            inputs: vec![TensorMetadata { name: "input".to_string(), datatype: "FP32".to_string(), shape: vec![-1] }],
+            outputs: vec![TensorMetadata { name: "output".to_string(), datatype: "FP32".to_string(), shape: vec![-1] }],
+        }))
+    }
+
+    /// Map `request`'s FP32 input tensor contents into `FeatureVector`s and
+    /// route them to `predict` (a single instance) or `predict_batch` (more
+    /// than one), reporting the serving model's version back via
+    /// `ModelInferResponse::parameters`
+    async fn model_infer(
+        &self,
+        request: Request<ModelInferRequest>,
+    ) -> Result<Response<ModelInferResponse>, Status> {
+        let request = request.into_inner();
+        let model_name = request.model_name.clone();
+
+        let input = request
+            .inputs
+            .first()
+            .ok_or_else(|| Status::invalid_argument("ModelInferRequest has no inputs"))?;
+        let contents = input
+            .contents
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("Input tensor has no contents"))?;
+        if contents.fp32_contents.is_empty() {
+            return Err(Status::invalid_argument("Only FP32 input tensor contents are supported"));
+        }
+
+        // Shape is `[..., feature_dim]`; the last dimension is the per-instance width
+        let feature_dim = *input
+            .shape
+            .last()
+            .ok_or_else(|| Status::invalid_argument("Input tensor has no shape"))? as usize;
+        if feature_dim == 0 || contents.fp32_contents.len() % feature_dim != 0 {
+            return Err(Status::invalid_argument(
+                "Input tensor contents don't evenly divide by the declared feature dimension",
+            ));
+        }
+
+        let instances: Vec<Vec<f32>> = contents.fp32_contents.chunks(feature_dim).map(|chunk| chunk.to_vec()).collect();
+
+        let (predictions, model_version) = if instances.len() == 1 {
+            let response = self
+                .api
+                .predict(&model_name, instances.into_iter().next().unwrap())
+                .await
+                .map_err(to_status)?;
+            (vec![response.prediction], response.model_version)
+        } else {
+            let response = self.api.predict_batch(&model_name, instances).await.map_err(to_status)?;
+            (response.predictions, response.model_version)
+        };
+
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            "model_version".to_string(),
+            InferParameter { parameter_choice: Some(ParameterChoice::Int64Param(model_version as i64)) },
+        );
+
+        Ok(Response::new(ModelInferResponse {
+            model_name,
+            model_version: model_version.to_string(),
+            id: request.id,
+            parameters,
+            outputs: vec![InferOutputTensor {
+                name: "output".to_string(),
+                datatype: "FP32".to_string(),
+                shape: vec![predictions.len() as i64],
+                contents: Some(InferTensorContents { fp32_contents: predictions, ..Default::default() }),
+            }],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::continuous_learning::ContinuousLearningConfig;
+    use kserve::ModelInferRequest as InferReq;
+
+    async fn test_server() -> ContinuumServer {
+        let api = Arc::new(ContinuumApi::new(ContinuousLearningConfig::default()));
+        api.register_model("test_model", "linear", None).await.unwrap();
+        api.add_training_example("test_model", vec![1.0], 2.0, false).await.unwrap();
+        api.add_training_example("test_model", vec![2.0], 4.0, false).await.unwrap();
+        api.train_model("test_model").await.unwrap();
+        ContinuumServer::new(api)
+    }
+
+    fn fp32_input(name: &str, shape: Vec<i64>, values: Vec<f32>) -> ModelInferRequest {
+        InferReq {
+            model_name: name.to_string(),
+            model_version: String::new(),
+            id: "req-1".to_string(),
+            inputs: vec![kserve::model_infer_request::InferInputTensor {
+                name: "input".to_string(),
+                datatype: "FP32".to_string(),
+                shape,
+                contents: Some(InferTensorContents { fp32_contents: values, ..Default::default() }),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_live_and_ready_are_always_true() {
+        let server = test_server().await;
+        assert!(server.server_live(Request::new(ServerLiveRequest {})).await.unwrap().into_inner().live);
+        assert!(server.server_ready(Request::new(ServerReadyRequest {})).await.unwrap().into_inner().ready);
+    }
+
+    #[tokio::test]
+    async fn test_model_ready_reflects_registration() {
+        let server = test_server().await;
+
+        let ready = server
+            .model_ready(Request::new(ModelReadyRequest { name: "test_model".to_string(), version: String::new() }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(ready.ready);
+
+        let not_ready = server
+            .model_ready(Request::new(ModelReadyRequest { name: "missing".to_string(), version: String::new() }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!not_ready.ready);
+    }
+
+    #[tokio::test]
+    async fn test_model_infer_single_instance_routes_to_predict() {
+        let server = test_server().await;
+
+        let response = server
+            .model_infer(Request::new(fp32_input("test_model", vec![1, 1], vec![5.0])))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.outputs.len(), 1);
+        let output = &response.outputs[0];
+        assert_eq!(output.contents.as_ref().unwrap().fp32_contents.len(), 1);
+        assert!((output.contents.as_ref().unwrap().fp32_contents[0] - 10.0).abs() < 0.5);
+        assert!(response.parameters.contains_key("model_version"));
+    }
+
+    #[tokio::test]
+    async fn test_model_infer_multi_instance_routes_to_predict_batch() {
+        let server = test_server().await;
+
+        let response = server
+            .model_infer(Request::new(fp32_input("test_model", vec![2, 1], vec![5.0, 6.0])))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.outputs[0].contents.as_ref().unwrap().fp32_contents.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_model_infer_rejects_unknown_model() {
+        let server = test_server().await;
+        let result = server.model_infer(Request::new(fp32_input("missing", vec![1, 1], vec![5.0]))).await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().code(), tonic::Code::NotFound);
+    }
+}
@@ -0,0 +1,271 @@
+//! HTTP/REST admin surface for `ModelServer`, gated behind the `http-api` feature.
+//!
+//! Model *types* are still registered in Rust (`ModelServer::register_model` is
+//! generic over `Model`), but everything that's runtime-controllable -- predicting,
+//! feeding training examples, forcing a training cycle, inspecting stats, and
+//! unregistering a model -- is reachable over the network so the continuous-learning
+//! loop can be operated from other services.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::traits::features::FeatureVector;
+use crate::traits::model::ModelError;
+use crate::server::server::ModelServer;
+
+/// Wraps `ModelError` so it can be returned directly from an axum handler,
+/// mapping each variant to a status code appropriate for an admin API
+struct ApiError(ModelError);
+
+impl From<ModelError> for ApiError {
+    fn from(err: ModelError) -> Self {
+        Self(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            ModelError::InvalidParameter(_) => StatusCode::NOT_FOUND,
+            ModelError::DimensionMismatch { .. } => StatusCode::BAD_REQUEST,
+            ModelError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            ModelError::TrainingError(_) => StatusCode::CONFLICT,
+            ModelError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            ModelError::PredictionError(_) | ModelError::SerializationError(_) | ModelError::IoError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        (status, Json(ErrorBody { error: self.0.to_string() })).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+#[derive(Deserialize)]
+struct PredictRequestBody {
+    features: Vec<f32>,
+}
+
+#[derive(Serialize)]
+struct PredictResponseBody {
+    prediction: f32,
+}
+
+#[derive(Deserialize)]
+struct PredictBatchRequestBody {
+    features: Vec<Vec<f32>>,
+}
+
+#[derive(Serialize)]
+struct PredictBatchResponseBody {
+    predictions: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct TrainExampleBody {
+    features: Vec<f32>,
+    target: f32,
+    #[serde(default)]
+    weight: Option<f32>,
+    #[serde(default)]
+    is_validation: bool,
+}
+
+#[derive(Serialize)]
+struct ModelsListResponse {
+    models: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct StatsResponse {
+    stats: String,
+}
+
+/// Build the admin router over a shared `ModelServer`
+pub fn router(server: Arc<ModelServer>) -> Router {
+    Router::new()
+        .route("/models", get(list_models))
+        .route("/models/:name", axum::routing::delete(unregister_model))
+        .route("/models/:name/predict", post(predict))
+        .route("/models/:name/predict_batch", post(predict_batch))
+        .route("/models/:name/train_examples", post(add_training_example))
+        .route("/models/:name/train_now", post(train_now))
+        .route("/models/:name/stats", get(get_model_stats))
+        .route("/metrics", get(metrics))
+        .with_state(server)
+}
+
+/// Render every registered model's stats as a Prometheus text-exposition payload,
+/// so this admin API can be scraped directly
+async fn metrics(State(server): State<Arc<ModelServer>>) -> String {
+    server.metrics_snapshot().await
+}
+
+async fn list_models(State(server): State<Arc<ModelServer>>) -> Json<ModelsListResponse> {
+    Json(ModelsListResponse { models: server.list_models().await })
+}
+
+async fn unregister_model(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    server.unregister_model(&name).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn predict(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+    Json(body): Json<PredictRequestBody>,
+) -> Result<Json<PredictResponseBody>, ApiError> {
+    let feature = FeatureVector::new(body.features);
+    let prediction = server.predict(&name, &feature).await?;
+    Ok(Json(PredictResponseBody { prediction }))
+}
+
+async fn predict_batch(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+    Json(body): Json<PredictBatchRequestBody>,
+) -> Result<Json<PredictBatchResponseBody>, ApiError> {
+    let features: Vec<FeatureVector> = body.features.into_iter().map(FeatureVector::new).collect();
+    let predictions = server.predict_batch(&name, &features).await?;
+    Ok(Json(PredictBatchResponseBody { predictions }))
+}
+
+async fn add_training_example(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+    Json(body): Json<TrainExampleBody>,
+) -> Result<StatusCode, ApiError> {
+    let feature = FeatureVector::new(body.features);
+    match body.weight {
+        Some(weight) => {
+            server
+                .add_training_example_weighted(&name, feature, body.target, weight, body.is_validation)
+                .await?
+        }
+        None => server.add_training_example(&name, feature, body.target, body.is_validation).await?,
+    }
+    Ok(StatusCode::CREATED)
+}
+
+async fn train_now(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    server.train_now(&name).await?;
+    Ok(StatusCode::OK)
+}
+
+async fn get_model_stats(
+    State(server): State<Arc<ModelServer>>,
+    Path(name): Path<String>,
+) -> Result<Json<StatsResponse>, ApiError> {
+    let stats = server.get_model_stats(&name).await?;
+    Ok(Json(StatsResponse { stats }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::linears::LinearRegression;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn test_server() -> Arc<ModelServer> {
+        let server = Arc::new(ModelServer::default());
+        server.register_model("test_model", LinearRegression::new(true, 0.01, 1000)).await.unwrap();
+        server
+    }
+
+    #[tokio::test]
+    async fn test_list_models_route() {
+        let app = router(test_server().await);
+
+        let response = app
+            .oneshot(Request::builder().uri("/models").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_route_exposes_registered_model() {
+        let app = router(test_server().await);
+
+        let response = app
+            .oneshot(Request::builder().uri("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert!(text.contains("continuum_model_version{model=\"test_model\"} 1"));
+    }
+
+    #[tokio::test]
+    async fn test_predict_route_unknown_model_returns_404() {
+        let app = router(test_server().await);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/models/missing/predict")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"features": [1.0]}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_train_examples_then_train_now_route() {
+        let app = router(test_server().await);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/models/test_model/train_examples")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"features": [1.0], "target": 2.0}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/models/test_model/train_now")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
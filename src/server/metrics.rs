@@ -1,50 +1,157 @@
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::SystemTime;
 
+use parking_lot::RwLock;
+
+use crate::server::cache_padded::CachePadded;
+
+/// Upper bounds for the prediction-latency histogram, in microseconds. Each bucket
+/// counts observations `<= bound` (standard Prometheus histogram semantics), so
+/// p50/p99 can be derived from the cumulative counts without storing raw samples.
+pub const LATENCY_HISTOGRAM_BUCKETS_US: [u64; 9] = [
+    100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000,
+];
+
+/// Lock-free mean/max aggregator for a latency signal, replacing a single racy
+/// "latest value" store that concurrent writers would otherwise overwrite with no
+/// ordering guarantee. `count`/`sum_us`/`max_us` are each cache-padded since they're
+/// written on every sample under concurrent `predict`/`train` calls.
+///
+/// All operations use `Ordering::Relaxed`: counters are independent and only ever
+/// read for reporting, so there's no second memory location whose visibility needs
+/// to be synchronized against them (contrast `ModelStats::version`, which gates
+/// actual model-swap visibility and is documented separately).
+#[derive(Debug, Default)]
+pub struct LatencyAggregator {
+    count: CachePadded<AtomicUsize>,
+    sum_us: CachePadded<AtomicU64>,
+    max_us: CachePadded<AtomicU64>,
+}
+
+impl LatencyAggregator {
+    /// Create a fresh, zeroed aggregator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one latency sample, in microseconds
+    pub fn record(&self, latency_us: usize) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(latency_us as u64, Ordering::Relaxed);
+        self.max_us.fetch_max(latency_us as u64, Ordering::Relaxed);
+    }
+
+    /// Number of samples recorded
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Running sum of all recorded samples, in microseconds
+    pub fn sum_us(&self) -> u64 {
+        self.sum_us.load(Ordering::Relaxed)
+    }
+
+    /// Mean latency across all recorded samples, in microseconds; `0.0` if none have
+    /// been recorded yet
+    pub fn mean_us(&self) -> f64 {
+        let count = self.count();
+        if count == 0 {
+            0.0
+        } else {
+            self.sum_us() as f64 / count as f64
+        }
+    }
+
+    /// Largest latency recorded, in microseconds
+    pub fn max_us(&self) -> u64 {
+        self.max_us.load(Ordering::Relaxed)
+    }
+}
+
 /// Container for tracking model statistics
+///
+/// Hot counters (incremented on every `predict`/`train` call) are each wrapped in
+/// `CachePadded` so concurrent updates to independent counters don't false-share a
+/// cache line. Reads use `Ordering::Relaxed`: these are independent monotonic
+/// counters read only for reporting (`format_stats`/`to_prometheus`), not used to
+/// synchronize access to other memory, so relaxed ordering is sufficient -- a reader
+/// may observe a slightly stale count, but never a torn or out-of-thin-air one.
 #[derive(Debug)]
 pub struct ModelStats {
     /// Total number of predictions made
-    pub prediction_count: AtomicUsize,
+    pub prediction_count: CachePadded<AtomicUsize>,
     /// Total number of training batches processed
-    pub training_count: AtomicUsize,
+    pub training_count: CachePadded<AtomicUsize>,
     /// Number of prediction errors
-    pub prediction_errors: AtomicUsize,
+    pub prediction_errors: CachePadded<AtomicUsize>,
     /// Number of training errors
-    pub training_errors: AtomicUsize,
-    /// Latest prediction latency in microseconds
-    pub latest_prediction_latency_us: AtomicUsize,
-    /// Latest training latency in microseconds
-    pub latest_training_latency_us: AtomicUsize,
+    pub training_errors: CachePadded<AtomicUsize>,
+    /// Mean/max aggregator for prediction latency
+    pub prediction_latency: LatencyAggregator,
+    /// Mean/max aggregator for training latency
+    pub training_latency: LatencyAggregator,
+    /// Mean/max aggregator for `Model::warmup` latency ahead of a swap
+    pub warmup_latency: LatencyAggregator,
+    /// Cumulative prediction-latency histogram, parallel to `LATENCY_HISTOGRAM_BUCKETS_US`
+    prediction_latency_buckets: Vec<AtomicUsize>,
     /// Model version
-    pub version: AtomicUsize,
+    pub version: CachePadded<AtomicUsize>,
+    /// Number of challenger retrains discarded by a `PromotionPolicy` instead of
+    /// being promoted via `AtomicModel::train_and_maybe_swap`
+    pub rejected_promotions: CachePadded<AtomicUsize>,
     /// Creation timestamp
     pub created_at: SystemTime,
     /// Last update timestamp
-    pub last_updated_at: AtomicU64,
+    last_updated_at: CachePadded<AtomicU64>,
 }
 
 impl ModelStats {
     /// Create new model statistics
     pub fn new() -> Self {
         Self {
-            prediction_count: AtomicUsize::new(0),
-            training_count: AtomicUsize::new(0),
-            prediction_errors: AtomicUsize::new(0),
-            training_errors: AtomicUsize::new(0),
-            latest_prediction_latency_us: AtomicUsize::new(0),
-            latest_training_latency_us: AtomicUsize::new(0),
-            version: AtomicUsize::new(1),
+            prediction_count: CachePadded::new(AtomicUsize::new(0)),
+            training_count: CachePadded::new(AtomicUsize::new(0)),
+            prediction_errors: CachePadded::new(AtomicUsize::new(0)),
+            training_errors: CachePadded::new(AtomicUsize::new(0)),
+            prediction_latency: LatencyAggregator::new(),
+            training_latency: LatencyAggregator::new(),
+            warmup_latency: LatencyAggregator::new(),
+            prediction_latency_buckets: LATENCY_HISTOGRAM_BUCKETS_US.iter().map(|_| AtomicUsize::new(0)).collect(),
+            version: CachePadded::new(AtomicUsize::new(1)),
+            rejected_promotions: CachePadded::new(AtomicUsize::new(0)),
             created_at: SystemTime::now(),
-            last_updated_at: AtomicU64::new(
+            last_updated_at: CachePadded::new(AtomicU64::new(
                 SystemTime::now()
                     .duration_since(SystemTime::UNIX_EPOCH)
                     .unwrap_or_default()
                     .as_secs(),
-            ),
+            )),
         }
     }
-    
+
+    /// Record a prediction latency sample, updating both the mean/max aggregator and
+    /// the cumulative histogram used by `to_prometheus`
+    pub fn record_prediction_latency(&self, latency_us: usize) {
+        self.prediction_latency.record(latency_us);
+
+        for (bound, bucket) in LATENCY_HISTOGRAM_BUCKETS_US.iter().zip(self.prediction_latency_buckets.iter()) {
+            if latency_us as u64 <= *bound {
+                bucket.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Record a training latency sample, updating the mean/max aggregator
+    pub fn record_training_latency(&self, latency_us: usize) {
+        self.training_latency.record(latency_us);
+    }
+
+    /// Record a model-warmup latency sample, updating the mean/max aggregator
+    pub fn record_warmup_latency(&self, latency_us: usize) {
+        self.warmup_latency.record(latency_us);
+    }
+
     /// Update the last updated timestamp
     pub fn update_timestamp(&self) {
         let now = SystemTime::now()
@@ -53,18 +160,20 @@ impl ModelStats {
             .as_secs();
         self.last_updated_at.store(now, Ordering::SeqCst);
     }
-    
+
     /// Get formatted statistics as a string
     pub fn format_stats(&self) -> String {
         format!(
-            "Model v{} | Predictions: {} | Training runs: {} | Errors: {}/{} | Latency: {}μs/{}μs",
+            "Model v{} | Predictions: {} | Training runs: {} | Errors: {}/{} | Pred latency (mean/max): {:.0}μs/{}μs | Train latency (mean/max): {:.0}μs/{}μs",
             self.version.load(Ordering::Relaxed),
             self.prediction_count.load(Ordering::Relaxed),
             self.training_count.load(Ordering::Relaxed),
             self.prediction_errors.load(Ordering::Relaxed),
             self.training_errors.load(Ordering::Relaxed),
-            self.latest_prediction_latency_us.load(Ordering::Relaxed),
-            self.latest_training_latency_us.load(Ordering::Relaxed),
+            self.prediction_latency.mean_us(),
+            self.prediction_latency.max_us(),
+            self.training_latency.mean_us(),
+            self.training_latency.max_us(),
         )
     }
     
@@ -91,6 +200,135 @@ impl ModelStats {
             .as_secs();
         now.saturating_sub(last_update)
     }
+
+    /// Render these stats as a Prometheus text-exposition payload for `model_name`,
+    /// suitable for appending directly into a scrape response
+    pub fn to_prometheus(&self, model_name: &str) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP continuum_prediction_total Total number of predictions made\n");
+        out.push_str("# TYPE continuum_prediction_total counter\n");
+        out.push_str(&format!(
+            "continuum_prediction_total{{model=\"{}\"}} {}\n",
+            model_name,
+            self.prediction_count.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_training_total Total number of training runs\n");
+        out.push_str("# TYPE continuum_training_total counter\n");
+        out.push_str(&format!(
+            "continuum_training_total{{model=\"{}\"}} {}\n",
+            model_name,
+            self.training_count.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_prediction_errors_total Total number of prediction errors\n");
+        out.push_str("# TYPE continuum_prediction_errors_total counter\n");
+        out.push_str(&format!(
+            "continuum_prediction_errors_total{{model=\"{}\"}} {}\n",
+            model_name,
+            self.prediction_errors.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_training_errors_total Total number of training errors\n");
+        out.push_str("# TYPE continuum_training_errors_total counter\n");
+        out.push_str(&format!(
+            "continuum_training_errors_total{{model=\"{}\"}} {}\n",
+            model_name,
+            self.training_errors.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_rejected_promotions_total Total number of challenger retrains discarded by a promotion policy\n");
+        out.push_str("# TYPE continuum_rejected_promotions_total counter\n");
+        out.push_str(&format!(
+            "continuum_rejected_promotions_total{{model=\"{}\"}} {}\n",
+            model_name,
+            self.rejected_promotions.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_prediction_latency_mean_us Mean prediction latency in microseconds\n");
+        out.push_str("# TYPE continuum_prediction_latency_mean_us gauge\n");
+        out.push_str(&format!(
+            "continuum_prediction_latency_mean_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.prediction_latency.mean_us()
+        ));
+
+        out.push_str("# HELP continuum_prediction_latency_max_us Largest observed prediction latency in microseconds\n");
+        out.push_str("# TYPE continuum_prediction_latency_max_us gauge\n");
+        out.push_str(&format!(
+            "continuum_prediction_latency_max_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.prediction_latency.max_us()
+        ));
+
+        out.push_str("# HELP continuum_training_latency_mean_us Mean training latency in microseconds\n");
+        out.push_str("# TYPE continuum_training_latency_mean_us gauge\n");
+        out.push_str(&format!(
+            "continuum_training_latency_mean_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.training_latency.mean_us()
+        ));
+
+        out.push_str("# HELP continuum_training_latency_max_us Largest observed training latency in microseconds\n");
+        out.push_str("# TYPE continuum_training_latency_max_us gauge\n");
+        out.push_str(&format!(
+            "continuum_training_latency_max_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.training_latency.max_us()
+        ));
+
+        out.push_str("# HELP continuum_warmup_latency_mean_us Mean model-warmup latency in microseconds, ahead of a swap\n");
+        out.push_str("# TYPE continuum_warmup_latency_mean_us gauge\n");
+        out.push_str(&format!(
+            "continuum_warmup_latency_mean_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.warmup_latency.mean_us()
+        ));
+
+        out.push_str("# HELP continuum_warmup_latency_max_us Largest observed model-warmup latency in microseconds\n");
+        out.push_str("# TYPE continuum_warmup_latency_max_us gauge\n");
+        out.push_str(&format!(
+            "continuum_warmup_latency_max_us{{model=\"{}\"}} {}\n",
+            model_name,
+            self.warmup_latency.max_us()
+        ));
+
+        out.push_str("# HELP continuum_model_version Current serving model version\n");
+        out.push_str("# TYPE continuum_model_version gauge\n");
+        out.push_str(&format!(
+            "continuum_model_version{{model=\"{}\"}} {}\n",
+            model_name,
+            self.version.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP continuum_prediction_latency_us_histogram Prediction latency distribution in microseconds, for deriving percentiles\n");
+        out.push_str("# TYPE continuum_prediction_latency_us_histogram histogram\n");
+        for (bound, bucket) in LATENCY_HISTOGRAM_BUCKETS_US.iter().zip(self.prediction_latency_buckets.iter()) {
+            out.push_str(&format!(
+                "continuum_prediction_latency_us_histogram_bucket{{model=\"{}\",le=\"{}\"}} {}\n",
+                model_name,
+                bound,
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        let total_predictions = self.prediction_count.load(Ordering::Relaxed);
+        out.push_str(&format!(
+            "continuum_prediction_latency_us_histogram_bucket{{model=\"{}\",le=\"+Inf\"}} {}\n",
+            model_name, total_predictions
+        ));
+        out.push_str(&format!(
+            "continuum_prediction_latency_us_histogram_sum{{model=\"{}\"}} {}\n",
+            model_name,
+            self.prediction_latency.sum_us()
+        ));
+        out.push_str(&format!(
+            "continuum_prediction_latency_us_histogram_count{{model=\"{}\"}} {}\n",
+            model_name, total_predictions
+        ));
+
+        out
+    }
 }
 
 impl Default for ModelStats {
@@ -99,6 +337,103 @@ impl Default for ModelStats {
     }
 }
 
+/// Request-level metrics spanning every `ContinuumApi` method, not just `predict`/`train`
+///
+/// Distinct from `ModelStats`, which only tracks prediction/training activity inside
+/// `AtomicModel`: `ApiMetrics` counts every `ContinuumApi` call (`register_model`,
+/// `list_models`, `get_model_info`, ...) regardless of whether it touches a model at
+/// all, so request volume and failure rate are observable at the API boundary itself.
+#[derive(Debug, Default)]
+pub struct ApiMetrics {
+    /// Total number of `ContinuumApi` calls made, across every method
+    total_requests: CachePadded<AtomicUsize>,
+    /// Number of calls whose `ApiResult` came back `Err`
+    total_failures: CachePadded<AtomicUsize>,
+    /// Number of calls made per `model_name`, for methods scoped to a single model
+    per_model_requests: RwLock<HashMap<String, AtomicUsize>>,
+    /// Mean/max aggregator for whole-call latency
+    request_latency: LatencyAggregator,
+    /// Cumulative request-latency histogram, parallel to `LATENCY_HISTOGRAM_BUCKETS_US`
+    request_latency_buckets: Vec<AtomicUsize>,
+}
+
+impl ApiMetrics {
+    /// Create a fresh, zeroed set of API metrics
+    pub fn new() -> Self {
+        Self {
+            total_requests: CachePadded::new(AtomicUsize::new(0)),
+            total_failures: CachePadded::new(AtomicUsize::new(0)),
+            per_model_requests: RwLock::new(HashMap::new()),
+            request_latency: LatencyAggregator::new(),
+            request_latency_buckets: LATENCY_HISTOGRAM_BUCKETS_US.iter().map(|_| AtomicUsize::new(0)).collect(),
+        }
+    }
+
+    /// Record one completed `ContinuumApi` call
+    ///
+    /// `model_name` is `None` for methods that aren't scoped to a single model (e.g.
+    /// `list_models`); `succeeded` reflects whether the call's `ApiResult` was `Ok`.
+    pub fn record_request(&self, model_name: Option<&str>, succeeded: bool, latency_us: usize) {
+        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        if !succeeded {
+            self.total_failures.fetch_add(1, Ordering::Relaxed);
+        }
+        self.request_latency.record(latency_us);
+        for (bound, bucket) in LATENCY_HISTOGRAM_BUCKETS_US.iter().zip(self.request_latency_buckets.iter()) {
+            if latency_us as u64 <= *bound {
+                bucket.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        if let Some(name) = model_name {
+            if let Some(counter) = self.per_model_requests.read().get(name) {
+                counter.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+            self.per_model_requests.write().entry(name.to_string()).or_insert_with(|| AtomicUsize::new(0)).fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Render these metrics as a Prometheus text-exposition payload
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP continuum_api_requests_total Total number of ContinuumApi calls made\n");
+        out.push_str("# TYPE continuum_api_requests_total counter\n");
+        out.push_str(&format!("continuum_api_requests_total {}\n", self.total_requests.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP continuum_api_request_failures_total Total number of ContinuumApi calls that returned an error\n");
+        out.push_str("# TYPE continuum_api_request_failures_total counter\n");
+        out.push_str(&format!("continuum_api_request_failures_total {}\n", self.total_failures.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP continuum_api_requests_by_model_total Total number of ContinuumApi calls scoped to a given model\n");
+        out.push_str("# TYPE continuum_api_requests_by_model_total counter\n");
+        for (model_name, counter) in self.per_model_requests.read().iter() {
+            out.push_str(&format!(
+                "continuum_api_requests_by_model_total{{model=\"{}\"}} {}\n",
+                model_name,
+                counter.load(Ordering::Relaxed)
+            ));
+        }
+
+        out.push_str("# HELP continuum_api_request_latency_us_histogram ContinuumApi call latency distribution in microseconds\n");
+        out.push_str("# TYPE continuum_api_request_latency_us_histogram histogram\n");
+        for (bound, bucket) in LATENCY_HISTOGRAM_BUCKETS_US.iter().zip(self.request_latency_buckets.iter()) {
+            out.push_str(&format!(
+                "continuum_api_request_latency_us_histogram_bucket{{le=\"{}\"}} {}\n",
+                bound,
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        let total = self.total_requests.load(Ordering::Relaxed);
+        out.push_str(&format!("continuum_api_request_latency_us_histogram_bucket{{le=\"+Inf\"}} {}\n", total));
+        out.push_str(&format!("continuum_api_request_latency_us_histogram_sum {}\n", self.request_latency.sum_us()));
+        out.push_str(&format!("continuum_api_request_latency_us_histogram_count {}\n", total));
+
+        out
+    }
+}
+
 /// Calculate KL divergence between two distributions
 /// Note: both arguments should be normalized probability distributions
 pub fn kl_divergence(p: &[f32], q: &[f32]) -> f32 {
@@ -149,6 +484,78 @@ mod tests {
         assert_eq!(stats.version.load(Ordering::Relaxed), 2);
     }
     
+    #[test]
+    fn test_to_prometheus_includes_labeled_metrics_and_histogram() {
+        let stats = ModelStats::new();
+        stats.prediction_count.fetch_add(3, Ordering::SeqCst);
+        stats.record_prediction_latency(50);
+        stats.record_prediction_latency(2_000);
+
+        let out = stats.to_prometheus("demo-model");
+
+        assert!(out.contains("continuum_prediction_total{model=\"demo-model\"} 3"));
+        assert!(out.contains("continuum_model_version{model=\"demo-model\"} 1"));
+        assert!(out.contains("continuum_prediction_latency_us_histogram_bucket{model=\"demo-model\",le=\"100\"} 1"));
+        assert!(out.contains("continuum_prediction_latency_us_histogram_bucket{model=\"demo-model\",le=\"5000\"} 2"));
+        assert!(out.contains("continuum_prediction_latency_us_histogram_bucket{model=\"demo-model\",le=\"+Inf\"} 3"));
+        assert!(out.contains("continuum_prediction_latency_us_histogram_sum{model=\"demo-model\"} 2050"));
+    }
+
+    #[test]
+    fn test_latency_aggregator_reports_mean_and_max() {
+        let aggregator = LatencyAggregator::new();
+        assert_eq!(aggregator.count(), 0);
+        assert_eq!(aggregator.mean_us(), 0.0);
+
+        aggregator.record(100);
+        aggregator.record(200);
+        aggregator.record(50);
+
+        assert_eq!(aggregator.count(), 3);
+        assert_eq!(aggregator.sum_us(), 350);
+        assert!((aggregator.mean_us() - (350.0 / 3.0)).abs() < 1e-9);
+        assert_eq!(aggregator.max_us(), 200);
+    }
+
+    #[test]
+    fn test_model_stats_format_stats_reports_mean_and_max_latency() {
+        let stats = ModelStats::new();
+        stats.record_prediction_latency(10);
+        stats.record_prediction_latency(30);
+        stats.record_training_latency(1_000);
+
+        let formatted = stats.format_stats();
+        assert!(formatted.contains("20"), "expected mean prediction latency of 20us in: {}", formatted);
+        assert!(formatted.contains("30"), "expected max prediction latency of 30us in: {}", formatted);
+        assert!(formatted.contains("1000"), "expected training latency of 1000us in: {}", formatted);
+    }
+
+    #[test]
+    fn test_api_metrics_tracks_totals_and_failures() {
+        let metrics = ApiMetrics::new();
+
+        metrics.record_request(Some("model_a"), true, 100);
+        metrics.record_request(Some("model_a"), false, 200);
+        metrics.record_request(None, true, 50);
+
+        let out = metrics.to_prometheus();
+        assert!(out.contains("continuum_api_requests_total 3"));
+        assert!(out.contains("continuum_api_request_failures_total 1"));
+        assert!(out.contains("continuum_api_requests_by_model_total{model=\"model_a\"} 2"));
+    }
+
+    #[test]
+    fn test_api_metrics_request_latency_histogram() {
+        let metrics = ApiMetrics::new();
+        metrics.record_request(Some("model_a"), true, 50);
+        metrics.record_request(Some("model_a"), true, 2_000);
+
+        let out = metrics.to_prometheus();
+        assert!(out.contains("continuum_api_request_latency_us_histogram_bucket{le=\"100\"} 1"));
+        assert!(out.contains("continuum_api_request_latency_us_histogram_bucket{le=\"5000\"} 2"));
+        assert!(out.contains("continuum_api_request_latency_us_histogram_sum 2050"));
+    }
+
     #[test]
     fn test_kl_divergence() {
         // Two identical distributions should have KL divergence of 0
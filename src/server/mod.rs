@@ -0,0 +1,16 @@
+pub mod api;
+pub mod batching;
+pub mod cache_padded;
+pub mod continuous_learning;
+pub mod drift;
+#[cfg(feature = "grpc-api")]
+pub mod grpc_api;
+#[cfg(feature = "http-api")]
+pub mod http_api;
+pub mod metrics;
+pub mod model_server;
+pub mod server;
+pub mod snapshot;
+pub mod swap_decision;
+pub mod training_history;
+pub mod tuner;
@@ -1,10 +1,92 @@
+use std::collections::VecDeque;
 use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 use parking_lot::{RwLock, Mutex};
+use tokio::sync::broadcast;
 
 use crate::traits::features::FeatureVector;
-use crate::traits::model::{Model, ModelError};
+use crate::traits::model::{IncrementalModel, Model, ModelError, SerializationFormat};
 use crate::server::metrics::ModelStats;
+use crate::server::snapshot::SnapshotStore;
+
+/// Default number of previously-swapped-in model versions retained for rollback
+const DEFAULT_MAX_HISTORY: usize = 5;
+/// Default capacity of `AtomicModel`'s `ModelEvent` broadcast channel; lagging
+/// subscribers drop oldest events rather than block publishers once this fills
+const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Kind of lifecycle event broadcast by `AtomicModel::subscribe`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelEventKind {
+    /// The training model was promoted to serving via `swap_models`
+    Swapped,
+    /// The serving model was restored to a previously-retained version via `rollback`
+    RolledBack,
+    /// A `train`/`train_weighted` call began
+    TrainingStarted,
+    /// A `train`/`train_weighted` call completed, successfully or not
+    TrainingFinished,
+}
+
+/// An event emitted on `AtomicModel`'s broadcast channel for every serving-relevant
+/// state change, so downstream components can react immediately instead of
+/// busy-polling `get_version()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelEvent {
+    /// The serving version at the time of this event
+    pub version: usize,
+    /// When this event occurred
+    pub timestamp: SystemTime,
+    /// What kind of event this is
+    pub kind: ModelEventKind,
+}
+
+/// Metadata describing one model version retained in `AtomicModel`'s rollback history
+#[derive(Debug, Clone)]
+pub struct VersionInfo {
+    /// The model version this entry represents
+    pub version: usize,
+    /// When this version was swapped in
+    pub trained_at: SystemTime,
+    /// Validation error recorded at swap time, if validation data was available
+    pub validation_error: Option<f32>,
+}
+
+/// Policy governing whether a freshly-trained challenger is promoted to serving in
+/// `AtomicModel::train_and_maybe_swap`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PromotionPolicy {
+    /// Always promote the challenger, regardless of validation error
+    Always,
+    /// Never promote automatically; the caller inspects `PromotionOutcome` and calls
+    /// `swap_models` itself if it decides to
+    Manual,
+    /// Promote only when the challenger's validation error improves on the
+    /// champion's by at least `min_delta` as a fraction of the champion's error
+    /// (e.g. `0.05` requires a 5% reduction)
+    RelativeImprovement { min_delta: f32 },
+}
+
+/// Outcome of a `train_and_maybe_swap` call
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PromotionOutcome {
+    /// Whether the challenger was promoted to serving
+    pub promoted: bool,
+    /// Champion (previously-serving) model's validation error
+    pub champion_error: f32,
+    /// Challenger (freshly trained) model's validation error
+    pub challenger_error: f32,
+    /// The new serving version, if `promoted`
+    pub new_version: Option<usize>,
+}
+
+/// A retained snapshot of a previously-swapped-in model version
+struct ModelVersionEntry<M> {
+    version: usize,
+    model: M,
+    trained_at: SystemTime,
+    validation_error: Option<f32>,
+}
 
 /// Atomic model container that enables zero-downtime updates
 pub struct AtomicModel<M: Model + Clone + Send + Sync + 'static> {
@@ -18,22 +100,55 @@ pub struct AtomicModel<M: Model + Clone + Send + Sync + 'static> {
     training_in_progress: AtomicBool,
     /// Models are the same?
     models_in_sync: AtomicBool,
+    /// Bounded ring buffer of the last `max_history` swapped-in versions, oldest first
+    history: Arc<Mutex<VecDeque<ModelVersionEntry<M>>>>,
+    /// Maximum number of versions retained in `history`
+    max_history: usize,
+    /// Set when a drift monitor determines the serving model's predictions have
+    /// drifted from its reference distribution; cleared on the next swap/rollback
+    stale: AtomicBool,
+    /// Broadcasts a `ModelEvent` on every swap/rollback/train; see `subscribe`
+    events: broadcast::Sender<ModelEvent>,
 }
 
 impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
     /// Create a new atomic model container
     pub fn new(initial_model: M) -> Self {
         let stats = Arc::new(ModelStats::new());
-        
+        let (events, _) = broadcast::channel(DEFAULT_EVENT_CHANNEL_CAPACITY);
+
         Self {
             current: Arc::new(RwLock::new(initial_model.clone())),
             training: Arc::new(Mutex::new(initial_model)),
             stats,
             training_in_progress: AtomicBool::new(false),
             models_in_sync: AtomicBool::new(true),
+            history: Arc::new(Mutex::new(VecDeque::new())),
+            max_history: DEFAULT_MAX_HISTORY,
+            stale: AtomicBool::new(false),
+            events,
         }
     }
-    
+
+    /// Subscribe to `ModelEvent`s emitted on every swap/rollback/train. Events
+    /// published before a receiver subscribes are not replayed, and a receiver that
+    /// falls more than `DEFAULT_EVENT_CHANNEL_CAPACITY` events behind silently skips
+    /// the ones it missed (see `tokio::sync::broadcast`'s lagging-receiver behavior)
+    pub fn subscribe(&self) -> broadcast::Receiver<ModelEvent> {
+        self.events.subscribe()
+    }
+
+    /// Publish a `ModelEvent`; a send with no active subscribers is a no-op
+    fn emit_event(&self, kind: ModelEventKind, version: usize) {
+        let _ = self.events.send(ModelEvent { version, timestamp: SystemTime::now(), kind });
+    }
+
+    /// Configure how many previously-swapped-in versions are retained for rollback
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history.max(1);
+        self
+    }
+
     /// Get a reference to the current model for predictions
     pub fn get_current(&self) -> Arc<RwLock<M>> {
         Arc::clone(&self.current)
@@ -58,7 +173,23 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
     pub fn is_in_sync(&self) -> bool {
         self.models_in_sync.load(Ordering::Relaxed)
     }
-    
+
+    /// Mark the serving model "stale", typically because a drift monitor detected
+    /// its prediction distribution has diverged from its reference
+    pub fn mark_stale(&self) {
+        self.stale.store(true, Ordering::SeqCst);
+    }
+
+    /// Check whether the serving model is currently marked stale
+    pub fn is_stale(&self) -> bool {
+        self.stale.load(Ordering::Relaxed)
+    }
+
+    /// Clear the stale flag, e.g. after a fresh version has been swapped in
+    pub fn clear_stale(&self) {
+        self.stale.store(false, Ordering::SeqCst);
+    }
+
     /// Update the training model with new data
     pub async fn train(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
         if features.is_empty() || targets.is_empty() {
@@ -80,36 +211,96 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
         
         // Models will be out of sync
         self.models_in_sync.store(false, Ordering::SeqCst);
-        
+        self.emit_event(ModelEventKind::TrainingStarted, self.get_version());
+
         // Record start time
         let start_time = Instant::now();
-        
+
         // Get exclusive access to training model
         let mut training_model = self.training.lock();
-        
+
         // Perform training
         let result = training_model.train(features, targets);
-        
+
         // Update stats
         match result {
             Ok(()) => {
                 self.stats.training_count.fetch_add(1, Ordering::SeqCst);
                 let duration = start_time.elapsed().as_micros() as usize;
-                self.stats.latest_training_latency_us.store(duration, Ordering::SeqCst);
+                self.stats.record_training_latency(duration);
                 self.stats.update_timestamp();
             }
             Err(_) => {
                 self.stats.training_errors.fetch_add(1, Ordering::SeqCst);
             }
         }
-        
+
         // Release training lock
         drop(training_model);
         self.training_in_progress.store(false, Ordering::SeqCst);
-        
+        self.emit_event(ModelEventKind::TrainingFinished, self.get_version());
+
         result
     }
-    
+
+    /// Update the training model with new data and optional per-sample weights
+    pub async fn train_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        if features.is_empty() || targets.is_empty() {
+            return Err(ModelError::TrainingError("Empty training data".to_string()));
+        }
+
+        if features.len() != targets.len() {
+            return Err(ModelError::DimensionMismatch {
+                expected: features.len(),
+                actual: targets.len(),
+                context: "features vs targets length".to_string(),
+            });
+        }
+
+        // Check if training is already in progress
+        if self.training_in_progress.swap(true, Ordering::SeqCst) {
+            return Err(ModelError::TrainingError("Training already in progress".to_string()));
+        }
+
+        // Models will be out of sync
+        self.models_in_sync.store(false, Ordering::SeqCst);
+        self.emit_event(ModelEventKind::TrainingStarted, self.get_version());
+
+        // Record start time
+        let start_time = Instant::now();
+
+        // Get exclusive access to training model
+        let mut training_model = self.training.lock();
+
+        // Perform training
+        let result = training_model.train_weighted(features, targets, sample_weights);
+
+        // Update stats
+        match result {
+            Ok(()) => {
+                self.stats.training_count.fetch_add(1, Ordering::SeqCst);
+                let duration = start_time.elapsed().as_micros() as usize;
+                self.stats.record_training_latency(duration);
+                self.stats.update_timestamp();
+            }
+            Err(_) => {
+                self.stats.training_errors.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // Release training lock
+        drop(training_model);
+        self.training_in_progress.store(false, Ordering::SeqCst);
+        self.emit_event(ModelEventKind::TrainingFinished, self.get_version());
+
+        result
+    }
+
     /// Make a prediction using the current model
     pub async fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
         // Record start time
@@ -126,8 +317,8 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
             Ok(prediction) => {
                 self.stats.prediction_count.fetch_add(1, Ordering::SeqCst);
                 let duration = start_time.elapsed().as_micros() as usize;
-                self.stats.latest_prediction_latency_us.store(duration, Ordering::SeqCst);
-                
+                self.stats.record_prediction_latency(duration);
+
                 Ok(prediction)
             }
             Err(err) => {
@@ -153,8 +344,8 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
             Ok(predictions) => {
                 self.stats.prediction_count.fetch_add(features.len(), Ordering::SeqCst);
                 let duration = start_time.elapsed().as_micros() as usize;
-                self.stats.latest_prediction_latency_us.store(duration / features.len().max(1), Ordering::SeqCst);
-                
+                self.stats.record_prediction_latency(duration / features.len().max(1));
+
                 Ok(predictions)
             }
             Err(err) => {
@@ -164,42 +355,259 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
         }
     }
     
-    /// Atomically swap training model to become current model
+    /// Atomically swap training model to become current model, retaining the
+    /// newly-current model in the bounded rollback history
+    ///
+    /// Runs the challenger through `Model::warmup` before writing it into `current`,
+    /// so any lazily-initialized state is primed while the old model still serves
+    /// traffic instead of on the first post-swap prediction. If the challenger was
+    /// already primed via `warmup_training`, this re-runs `warmup` against it; a
+    /// well-behaved `warmup` implementation should be cheap to repeat.
     pub fn swap_models(&self) -> Result<usize, ModelError> {
         if self.is_training() {
             return Err(ModelError::TrainingError("Cannot swap while training in progress".to_string()));
         }
-        
+
         // Create a clone of the training model
         let new_model = {
             let training_guard = self.training.lock();
             training_guard.clone()
         };
-        
+
+        // Prime any lazily-initialized state while the old model still serves
+        let warmup_start = Instant::now();
+        new_model.warmup()?;
+        self.stats.record_warmup_latency(warmup_start.elapsed().as_micros() as usize);
+
         // Update the current model
         {
             let mut current_guard = self.current.write();
-            *current_guard = new_model;
+            *current_guard = new_model.clone();
         }
-        
+
         // Increment version
         let new_version = self.stats.version.fetch_add(1, Ordering::SeqCst) + 1;
-        
+
         // Update timestamp
         self.stats.update_timestamp();
-        
-        // Mark models as in sync
+
+        // Mark models as in sync, and clear any previously-recorded drift staleness
         self.models_in_sync.store(true, Ordering::SeqCst);
-        
+        self.stale.store(false, Ordering::SeqCst);
+
+        // Retain this version for rollback, dropping the oldest once the ring is full
+        {
+            let mut history = self.history.lock();
+            history.push_back(ModelVersionEntry {
+                version: new_version,
+                model: new_model,
+                trained_at: SystemTime::now(),
+                validation_error: None,
+            });
+            while history.len() > self.max_history {
+                history.pop_front();
+            }
+        }
+
+        self.emit_event(ModelEventKind::Swapped, new_version);
+
         Ok(new_version)
     }
-    
+
+    /// Attach a validation error to a retained version's history entry; a no-op if
+    /// that version has since been evicted from the ring buffer
+    pub fn record_validation_error(&self, version: usize, validation_error: f32) {
+        let mut history = self.history.lock();
+        if let Some(entry) = history.iter_mut().find(|entry| entry.version == version) {
+            entry.validation_error = Some(validation_error);
+        }
+    }
+
+    /// List metadata for every version currently retained in the rollback history,
+    /// oldest first
+    pub fn list_versions(&self) -> Vec<VersionInfo> {
+        let history = self.history.lock();
+        history
+            .iter()
+            .map(|entry| VersionInfo {
+                version: entry.version,
+                trained_at: entry.trained_at,
+                validation_error: entry.validation_error,
+            })
+            .collect()
+    }
+
+    /// Retrieve a clone of a previously-retained version's model without touching
+    /// `current`/`training`; use `rollback` to actually restore it into service
+    pub fn get_model_at(&self, version: usize) -> Result<M, ModelError> {
+        let history = self.history.lock();
+        history
+            .iter()
+            .find(|entry| entry.version == version)
+            .map(|entry| entry.model.clone())
+            .ok_or_else(|| ModelError::InvalidParameter(format!("Version {} is not retained in history", version)))
+    }
+
+    /// Drop any retained history entries ahead of `version`, so a subsequent
+    /// `swap_models` can't derive a version number that collides with one of them
+    ///
+    /// Called by `rollback`/`restore_snapshot`: both rewind `stats.version` to a
+    /// prior point, but `swap_models` always derives the next version as
+    /// `stats.version + 1` -- without this truncation, a swap right after a rollback
+    /// would re-mint a version number still held by a later, now-abandoned history
+    /// entry (and, via `ModelServer::snapshot_version`, silently overwrite that
+    /// entry's on-disk snapshot with the new model's bytes).
+    fn truncate_history_after(&self, version: usize) {
+        let mut history = self.history.lock();
+        history.retain(|entry| entry.version <= version);
+    }
+
+    /// Roll the serving (current) model back to a previously-retained version
+    pub fn rollback(&self, target_version: usize) -> Result<usize, ModelError> {
+        if self.is_training() {
+            return Err(ModelError::TrainingError("Cannot rollback while training in progress".to_string()));
+        }
+
+        let restored = self.get_model_at(target_version)?;
+
+        {
+            let mut current_guard = self.current.write();
+            *current_guard = restored.clone();
+        }
+        {
+            let mut training_guard = self.training.lock();
+            *training_guard = restored;
+        }
+
+        self.stats.version.store(target_version, Ordering::SeqCst);
+        self.stats.update_timestamp();
+        self.models_in_sync.store(true, Ordering::SeqCst);
+        self.stale.store(false, Ordering::SeqCst);
+        self.truncate_history_after(target_version);
+
+        self.emit_event(ModelEventKind::RolledBack, target_version);
+
+        Ok(target_version)
+    }
+
+    /// Serialize the currently-serving model into `store` as `version`, so it can be
+    /// recovered via `restore_snapshot` after a process restart wipes `history`
+    pub fn save_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<(), ModelError> {
+        let current = self.current.read();
+        store.save(version, &*current)
+    }
+
+    /// Restore `version` from `store` into both the serving and training model. Unlike
+    /// `rollback`, this doesn't require `version` to still be retained in the
+    /// in-memory `history` ring, so it can recover a version from before a restart
+    pub fn restore_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<usize, ModelError> {
+        if self.is_training() {
+            return Err(ModelError::TrainingError("Cannot restore a snapshot while training in progress".to_string()));
+        }
+
+        let mut restored = self.current.read().clone();
+        store.load(version, &mut restored)?;
+
+        {
+            let mut current_guard = self.current.write();
+            *current_guard = restored.clone();
+        }
+        {
+            let mut training_guard = self.training.lock();
+            *training_guard = restored;
+        }
+
+        self.stats.version.store(version, Ordering::SeqCst);
+        self.stats.update_timestamp();
+        self.models_in_sync.store(true, Ordering::SeqCst);
+        self.stale.store(false, Ordering::SeqCst);
+        self.truncate_history_after(version);
+
+        self.emit_event(ModelEventKind::RolledBack, version);
+
+        Ok(version)
+    }
+
+    /// Serialize the currently-serving model directly to `path`, independent of any
+    /// `SnapshotStore` ring -- for ad hoc export/backup outside the bounded rollback
+    /// history
+    pub fn save_to_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        self.current.read().save_as(path, format)
+    }
+
+    /// Deserialize `path` into both the serving and training model, independent of
+    /// any `SnapshotStore` ring
+    pub fn load_from_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        if self.is_training() {
+            return Err(ModelError::TrainingError("Cannot load a model while training is in progress".to_string()));
+        }
+
+        let mut restored = self.current.read().clone();
+        restored.load_from(path, format)?;
+
+        {
+            let mut current_guard = self.current.write();
+            *current_guard = restored.clone();
+        }
+        {
+            let mut training_guard = self.training.lock();
+            *training_guard = restored;
+        }
+
+        self.models_in_sync.store(true, Ordering::SeqCst);
+        self.stale.store(false, Ordering::SeqCst);
+        self.stats.update_timestamp();
+
+        Ok(())
+    }
+
+    /// Import parameters (e.g. weights produced by a hyperparameter-tuning trial)
+    /// directly into the training model, ready to be promoted via `swap_models`
+    pub fn import_training_parameters(&self, parameters: Vec<f32>) -> Result<(), ModelError> {
+        let mut training = self.training.lock();
+        training.import_parameters(parameters)
+    }
+
+    /// Prime the training model via `Model::warmup` (and, if `sample` is given,
+    /// `iterations` extra `predict` calls against it) without promoting it to
+    /// serving. Lets a caller overlap the warmup cost with the old model still
+    /// serving traffic, then call `swap_models` once this returns -- `swap_models`
+    /// re-runs `warmup` regardless, but a well-behaved implementation is cheap once
+    /// its state is already primed.
+    pub fn warmup_training(&self, sample: Option<&FeatureVector>, iterations: usize) -> Result<(), ModelError> {
+        let start = Instant::now();
+
+        let training_model = self.training.lock();
+        training_model.warmup()?;
+        if let Some(feature) = sample {
+            for _ in 0..iterations {
+                training_model.predict(feature)?;
+            }
+        }
+        drop(training_model);
+
+        self.stats.record_warmup_latency(start.elapsed().as_micros() as usize);
+        Ok(())
+    }
+
     /// Validate current model performance
     pub async fn validate(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError> {
         let current_model = self.current.read();
         current_model.validate(features, targets)
     }
-    
+
+    /// Validate current model performance with optional per-sample weights (e.g.
+    /// recency-decayed validation importance; see `TrainingBuffer::get_validation_data_weighted`)
+    pub async fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        let current_model = self.current.read();
+        current_model.validate_weighted(features, targets, sample_weights)
+    }
+
     /// Compare performance between current and training models
     pub async fn compare_models(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(f32, f32), ModelError> {
         if features.is_empty() || targets.is_empty() {
@@ -225,9 +633,70 @@ impl<M: Model + Clone + Send + Sync + 'static> AtomicModel<M> {
             let training_model = self.training.lock();
             training_model.validate(features, targets)?
         };
-        
+
         Ok((current_error, training_error))
     }
+
+    /// Compare raw predictions between the current and training models over the same
+    /// features, without swapping; used by `crate::server::swap_decision` to compare
+    /// the incumbent's and a candidate's predictive distributions ahead of a swap
+    pub async fn compare_predictions(&self, features: &[FeatureVector]) -> Result<(Vec<f32>, Vec<f32>), ModelError> {
+        if features.is_empty() {
+            return Err(ModelError::ValidationError("Empty validation data".to_string()));
+        }
+
+        let current_predictions = {
+            let current_model = self.current.read();
+            current_model.predict_batch(features)?
+        };
+
+        let training_predictions = {
+            let training_model = self.training.lock();
+            training_model.predict_batch(features)?
+        };
+
+        Ok((current_predictions, training_predictions))
+    }
+
+    /// Train the challenger on `train`/`train_targets`, validate both it and the
+    /// current champion on the same held-out `val`/`val_targets`, and promote the
+    /// challenger via `swap_models` only when `policy` clears it. A rejected
+    /// promotion still counts as a successful call -- it's recorded in
+    /// `ModelStats::rejected_promotions` rather than surfaced as an error.
+    pub async fn train_and_maybe_swap(
+        &self,
+        train: &[FeatureVector],
+        train_targets: &[f32],
+        val: &[FeatureVector],
+        val_targets: &[f32],
+        policy: PromotionPolicy,
+    ) -> Result<PromotionOutcome, ModelError> {
+        self.train(train, train_targets).await?;
+
+        let (champion_error, challenger_error) = self.compare_models(val, val_targets).await?;
+
+        let should_promote = match policy {
+            PromotionPolicy::Always => true,
+            PromotionPolicy::Manual => false,
+            PromotionPolicy::RelativeImprovement { min_delta } => {
+                challenger_error <= champion_error * (1.0 - min_delta)
+            }
+        };
+
+        let new_version = if should_promote {
+            Some(self.swap_models()?)
+        } else {
+            self.stats.rejected_promotions.fetch_add(1, Ordering::SeqCst);
+            None
+        };
+
+        Ok(PromotionOutcome {
+            promoted: should_promote,
+            champion_error,
+            challenger_error,
+            new_version,
+        })
+    }
 }
 
 /// Implement Clone for AtomicModel
@@ -235,13 +704,17 @@ impl<M: Model + Clone + Send + Sync + 'static> Clone for AtomicModel<M> {
     fn clone(&self) -> Self {
         let current = self.current.read().clone();
         let training = self.training.lock().clone();
-        
+
         Self {
             current: Arc::new(RwLock::new(current)),
             training: Arc::new(Mutex::new(training)),
             stats: Arc::clone(&self.stats),
             training_in_progress: AtomicBool::new(self.is_training()),
             models_in_sync: AtomicBool::new(self.is_in_sync()),
+            history: Arc::clone(&self.history),
+            max_history: self.max_history,
+            stale: AtomicBool::new(self.is_stale()),
+            events: self.events.clone(),
         }
     }
 }
@@ -251,16 +724,93 @@ impl<M: Model + Clone + Send + Sync + 'static> Clone for AtomicModel<M> {
 pub trait ModelWrapper: Send + Sync {
     /// Make a prediction
     async fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError>;
-    
+
+    /// Make predictions for multiple feature vectors in a single batched forward pass
+    async fn predict_batch(&self, features: &[FeatureVector]) -> Result<Vec<f32>, ModelError>;
+
     /// Train the model
     async fn train(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError>;
-    
+
+    /// Train the model with optional per-sample weights
+    async fn train_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError>;
+
     /// Swap current and training models
     fn swap_models(&self) -> Result<usize, ModelError>;
-    
+
+    /// Prime the training model via `Model::warmup` without promoting it to serving
+    fn warmup_training(&self, sample: Option<&FeatureVector>, iterations: usize) -> Result<(), ModelError>;
+
+    /// Attach a validation error to a retained version's history entry
+    fn record_validation_error(&self, version: usize, validation_error: f32);
+
+    /// List metadata for every version currently retained in the rollback history
+    fn list_versions(&self) -> Vec<VersionInfo>;
+
+    /// Roll the serving (current) model back to a previously-retained version
+    fn rollback(&self, target_version: usize) -> Result<usize, ModelError>;
+
+    /// Subscribe to `ModelEvent`s emitted on every swap/rollback/train
+    fn subscribe(&self) -> broadcast::Receiver<ModelEvent>;
+
+    /// Mark the serving model "stale" (e.g. due to detected prediction drift)
+    fn mark_stale(&self);
+
+    /// Check whether the serving model is currently marked stale
+    fn is_stale(&self) -> bool;
+
+    /// Import parameters directly into the training model, ready to be promoted
+    /// via `swap_models` (used to land a hyperparameter-tuning trial's weights)
+    fn import_training_parameters(&self, parameters: Vec<f32>) -> Result<(), ModelError>;
+
+    /// Serialize the currently-serving model into `store` as `version`
+    fn save_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<(), ModelError>;
+
+    /// Restore `version` from `store` into both the serving and training model, even
+    /// if it's no longer retained in the in-memory rollback history
+    fn restore_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<usize, ModelError>;
+
+    /// Serialize the currently-serving model directly to `path`, independent of any
+    /// `SnapshotStore` ring
+    fn save_to_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
+    /// Deserialize `path` into both the serving and training model, independent of
+    /// any `SnapshotStore` ring
+    fn load_from_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
     /// Validate model performance
     async fn validate(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError>;
-    
+
+    /// Validate model performance with optional per-sample weights
+    async fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError>;
+
+    /// Compare validation error between the current and training models, without
+    /// swapping
+    async fn compare_models(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(f32, f32), ModelError>;
+
+    /// Compare raw predictions between the current and training models over the same
+    /// features, without swapping
+    async fn compare_predictions(&self, features: &[FeatureVector]) -> Result<(Vec<f32>, Vec<f32>), ModelError>;
+
+    /// Train a challenger and promote it to serving only if `policy` clears it
+    async fn train_and_maybe_swap(
+        &self,
+        train: &[FeatureVector],
+        train_targets: &[f32],
+        val: &[FeatureVector],
+        val_targets: &[f32],
+        policy: PromotionPolicy,
+    ) -> Result<PromotionOutcome, ModelError>;
+
     /// Get model version
     fn get_version(&self) -> usize;
     
@@ -269,6 +819,9 @@ pub trait ModelWrapper: Send + Sync {
     
     /// Get model stats as formatted string
     fn get_stats_formatted(&self) -> String;
+
+    /// Render model stats as a Prometheus exposition payload for `model_name`
+    fn get_stats_prometheus(&self, model_name: &str) -> String;
 }
 
 /// Implementation of ModelWrapper for AtomicModel
@@ -277,19 +830,108 @@ impl<M: Model + Clone + Send + Sync + 'static> ModelWrapper for AtomicModel<M> {
     async fn predict(&self, feature: &FeatureVector) -> Result<f32, ModelError> {
         self.predict(feature).await
     }
-    
+
+    async fn predict_batch(&self, features: &[FeatureVector]) -> Result<Vec<f32>, ModelError> {
+        self.predict_batch(features).await
+    }
+
     async fn train(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
         self.train(features, targets).await
     }
-    
+
+    async fn train_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        self.train_weighted(features, targets, sample_weights).await
+    }
+
     fn swap_models(&self) -> Result<usize, ModelError> {
         self.swap_models()
     }
-    
+
+    fn warmup_training(&self, sample: Option<&FeatureVector>, iterations: usize) -> Result<(), ModelError> {
+        self.warmup_training(sample, iterations)
+    }
+
+    fn record_validation_error(&self, version: usize, validation_error: f32) {
+        self.record_validation_error(version, validation_error)
+    }
+
+    fn list_versions(&self) -> Vec<VersionInfo> {
+        self.list_versions()
+    }
+
+    fn rollback(&self, target_version: usize) -> Result<usize, ModelError> {
+        self.rollback(target_version)
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<ModelEvent> {
+        self.subscribe()
+    }
+
+    fn mark_stale(&self) {
+        self.mark_stale()
+    }
+
+    fn is_stale(&self) -> bool {
+        self.is_stale()
+    }
+
+    fn import_training_parameters(&self, parameters: Vec<f32>) -> Result<(), ModelError> {
+        self.import_training_parameters(parameters)
+    }
+
+    fn save_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<(), ModelError> {
+        self.save_snapshot(store, version)
+    }
+
+    fn restore_snapshot(&self, store: &SnapshotStore, version: usize) -> Result<usize, ModelError> {
+        self.restore_snapshot(store, version)
+    }
+
+    fn save_to_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        self.save_to_path(path, format)
+    }
+
+    fn load_from_path(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        self.load_from_path(path, format)
+    }
+
     async fn validate(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError> {
         self.validate(features, targets).await
     }
-    
+
+    async fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        self.validate_weighted(features, targets, sample_weights).await
+    }
+
+    async fn compare_models(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(f32, f32), ModelError> {
+        self.compare_models(features, targets).await
+    }
+
+    async fn compare_predictions(&self, features: &[FeatureVector]) -> Result<(Vec<f32>, Vec<f32>), ModelError> {
+        self.compare_predictions(features).await
+    }
+
+    async fn train_and_maybe_swap(
+        &self,
+        train: &[FeatureVector],
+        train_targets: &[f32],
+        val: &[FeatureVector],
+        val_targets: &[f32],
+        policy: PromotionPolicy,
+    ) -> Result<PromotionOutcome, ModelError> {
+        self.train_and_maybe_swap(train, train_targets, val, val_targets, policy).await
+    }
+
     fn get_version(&self) -> usize {
         self.get_version()
     }
@@ -301,6 +943,47 @@ impl<M: Model + Clone + Send + Sync + 'static> ModelWrapper for AtomicModel<M> {
     fn get_stats_formatted(&self) -> String {
         self.stats.format_stats()
     }
+
+    fn get_stats_prometheus(&self, model_name: &str) -> String {
+        self.stats.to_prometheus(model_name)
+    }
+}
+
+/// Exposes `IncrementalModel::update` through a type-erased handle, so
+/// `ModelServer::add_training_example`/`add_training_example_weighted` can apply an
+/// online update without knowing the concrete model type
+///
+/// Kept separate from `ModelWrapper` rather than added as a method there, since only
+/// some models implement `IncrementalModel` (see `ModelServer::register_model_with_incremental_updates`,
+/// which is the only place this trait is implemented against)
+#[async_trait::async_trait]
+pub trait IncrementalModelWrapper: Send + Sync {
+    /// Apply an online update to both the serving and training model copies, keeping
+    /// them in step without going through `train`/`swap_models`
+    async fn update_incremental(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError>;
+}
+
+#[async_trait::async_trait]
+impl<M: Model + IncrementalModel + Clone + Send + Sync + 'static> IncrementalModelWrapper for AtomicModel<M> {
+    async fn update_incremental(&self, features: &[FeatureVector], targets: &[f32]) -> Result<(), ModelError> {
+        if self.is_training() {
+            return Err(ModelError::TrainingError(
+                "Cannot apply an incremental update while a batch retrain is in progress".to_string(),
+            ));
+        }
+
+        {
+            let mut current_guard = self.current.write();
+            current_guard.update(features, targets)?;
+        }
+        {
+            let mut training_guard = self.training.lock();
+            training_guard.update(features, targets)?;
+        }
+
+        self.stats.update_timestamp();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -498,10 +1181,301 @@ mod tests {
         ];
         
         let predictions = atomic_model.predict_batch(&test_features).await.unwrap();
-        
+
         // Predictions should be close to y = 2x + 1
         assert!((predictions[0] - 3.0).abs() < 1.0);
         assert!((predictions[1] - 5.0).abs() < 1.0);
         assert!((predictions[2] - 7.0).abs() < 1.0);
     }
+
+    #[tokio::test]
+    async fn test_atomic_model_rollback_restores_previous_version() {
+        let atomic_model = AtomicModel::new(create_test_model());
+
+        // Version 2: import_parameters(vec![0.0, 1.0]) via direct lock access on training
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 1.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 2
+
+        // Version 3
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 2.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 3
+
+        assert_eq!(atomic_model.get_version(), 3);
+
+        let versions = atomic_model.list_versions();
+        assert_eq!(versions.iter().map(|v| v.version).collect::<Vec<_>>(), vec![2, 3]);
+
+        let restored_version = atomic_model.rollback(2).unwrap();
+        assert_eq!(restored_version, 2);
+        assert_eq!(atomic_model.get_version(), 2);
+
+        let prediction = atomic_model.predict(&FeatureVector::new(vec![5.0])).await.unwrap();
+        assert!((prediction - 5.0).abs() < 1e-4); // y = 0 + 1*5
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_swap_after_rollback_does_not_collide_with_abandoned_version() {
+        let atomic_model = AtomicModel::new(create_test_model());
+
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 1.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 2
+
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 2.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 3 (the "bad" retrain)
+
+        atomic_model.rollback(2).unwrap(); // back out the bad version 3
+
+        // A later retrain re-mints version 3 -- the abandoned, pre-rollback version 3
+        // entry must not still be sitting in history under the same number
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 3.0]).unwrap();
+        }
+        let new_version = atomic_model.swap_models().unwrap();
+        assert_eq!(new_version, 3);
+
+        let versions = atomic_model.list_versions();
+        assert_eq!(versions.iter().map(|v| v.version).collect::<Vec<_>>(), vec![2, 3]);
+
+        // get_model_at(3) must resolve to the new retrain, not the abandoned one
+        let resolved = atomic_model.get_model_at(3).unwrap();
+        let prediction = resolved.predict(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!((prediction - 15.0).abs() < 1e-4); // y = 0 + 3*5, not 0 + 2*5
+
+        let current_prediction = atomic_model.predict(&FeatureVector::new(vec![5.0])).await.unwrap();
+        assert!((current_prediction - 15.0).abs() < 1e-4);
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_get_model_at_does_not_mutate_current() {
+        let atomic_model = AtomicModel::new(create_test_model());
+
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 1.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 2
+
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 2.0]).unwrap();
+        }
+        atomic_model.swap_models().unwrap(); // -> version 3
+
+        // Fetching an older version's model hands back a clone, without rolling
+        // current/training back to it
+        let old_model = atomic_model.get_model_at(2).unwrap();
+        let prediction = old_model.predict(&FeatureVector::new(vec![5.0])).unwrap();
+        assert!((prediction - 5.0).abs() < 1e-4); // y = 0 + 1*5
+
+        assert_eq!(atomic_model.get_version(), 3);
+        let current_prediction = atomic_model.predict(&FeatureVector::new(vec![5.0])).await.unwrap();
+        assert!((current_prediction - 10.0).abs() < 1e-4); // y = 0 + 2*5
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_get_model_at_rejects_unknown_version() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        atomic_model.swap_models().unwrap();
+
+        let result = atomic_model.get_model_at(99);
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_rollback_rejects_unknown_version() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        atomic_model.swap_models().unwrap();
+
+        let result = atomic_model.rollback(99);
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_history_evicts_oldest_beyond_max_history() {
+        let atomic_model = AtomicModel::new(create_test_model()).with_max_history(2);
+
+        atomic_model.swap_models().unwrap(); // version 2
+        atomic_model.swap_models().unwrap(); // version 3
+        atomic_model.swap_models().unwrap(); // version 4, evicts version 2
+
+        let versions = atomic_model.list_versions();
+        assert_eq!(versions.iter().map(|v| v.version).collect::<Vec<_>>(), vec![3, 4]);
+        assert!(atomic_model.rollback(2).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_stale_flag_cleared_on_swap() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        assert!(!atomic_model.is_stale());
+
+        atomic_model.mark_stale();
+        assert!(atomic_model.is_stale());
+
+        atomic_model.swap_models().unwrap();
+        assert!(!atomic_model.is_stale(), "swap should clear the stale flag");
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_record_validation_error() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        let version = atomic_model.swap_models().unwrap();
+
+        atomic_model.record_validation_error(version, 0.42);
+
+        let versions = atomic_model.list_versions();
+        assert_eq!(versions.last().unwrap().validation_error, Some(0.42));
+    }
+
+    #[tokio::test]
+    async fn test_train_and_maybe_swap_promotes_on_sufficient_improvement() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        let (features, targets) = create_test_data();
+        let val_features = vec![FeatureVector::new(vec![1.5])];
+        let val_targets = vec![4.0]; // y = 2*1.5 + 1
+
+        let outcome = atomic_model
+            .train_and_maybe_swap(&features, &targets, &val_features, &val_targets, PromotionPolicy::RelativeImprovement { min_delta: 0.01 })
+            .await
+            .unwrap();
+
+        assert!(outcome.promoted);
+        assert_eq!(outcome.new_version, Some(2));
+        assert!(outcome.challenger_error < outcome.champion_error);
+        assert_eq!(atomic_model.get_version(), 2);
+        assert_eq!(atomic_model.get_stats().rejected_promotions.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_train_and_maybe_swap_rejects_when_improvement_too_small() {
+        let atomic_model = AtomicModel::new(create_trained_model());
+        let (features, targets) = create_test_data();
+        let val_features = vec![FeatureVector::new(vec![1.5])];
+        let val_targets = vec![4.0];
+
+        // A min_delta above 1.0 asks for a negative error, which can never be cleared,
+        // so the challenger is discarded regardless of how much it actually improved
+        let outcome = atomic_model
+            .train_and_maybe_swap(&features, &targets, &val_features, &val_targets, PromotionPolicy::RelativeImprovement { min_delta: 2.0 })
+            .await
+            .unwrap();
+
+        assert!(!outcome.promoted);
+        assert_eq!(outcome.new_version, None);
+        assert_eq!(atomic_model.get_version(), 1);
+        assert_eq!(atomic_model.get_stats().rejected_promotions.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_train_and_maybe_swap_manual_policy_never_promotes() {
+        let atomic_model = AtomicModel::new(create_trained_model());
+        let (features, targets) = create_test_data();
+        let val_features = vec![FeatureVector::new(vec![1.5])];
+        let val_targets = vec![4.0];
+
+        let outcome = atomic_model
+            .train_and_maybe_swap(&features, &targets, &val_features, &val_targets, PromotionPolicy::Manual)
+            .await
+            .unwrap();
+
+        assert!(!outcome.promoted);
+        assert_eq!(atomic_model.get_version(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_subscribe_receives_training_and_swap_events() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        let mut events = atomic_model.subscribe();
+
+        let (features, targets) = create_test_data();
+        atomic_model.train(&features, &targets).await.unwrap();
+        atomic_model.swap_models().unwrap();
+
+        let started = events.recv().await.unwrap();
+        assert_eq!(started.kind, ModelEventKind::TrainingStarted);
+        assert_eq!(started.version, 1);
+
+        let finished = events.recv().await.unwrap();
+        assert_eq!(finished.kind, ModelEventKind::TrainingFinished);
+        assert_eq!(finished.version, 1);
+
+        let swapped = events.recv().await.unwrap();
+        assert_eq!(swapped.kind, ModelEventKind::Swapped);
+        assert_eq!(swapped.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_subscribe_receives_rollback_event() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        atomic_model.swap_models().unwrap(); // -> version 2
+        atomic_model.swap_models().unwrap(); // -> version 3
+
+        let mut events = atomic_model.subscribe();
+        atomic_model.rollback(2).unwrap();
+
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.kind, ModelEventKind::RolledBack);
+        assert_eq!(event.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_swap_models_records_warmup_latency() {
+        let atomic_model = AtomicModel::new(create_test_model());
+        assert_eq!(atomic_model.get_stats().warmup_latency.count(), 0);
+
+        atomic_model.swap_models().unwrap();
+
+        // The default `Model::warmup` is a no-op, but `swap_models` still runs and
+        // times it, so the sample count should reflect one warmup per swap
+        assert_eq!(atomic_model.get_stats().warmup_latency.count(), 1);
+
+        atomic_model.swap_models().unwrap();
+        assert_eq!(atomic_model.get_stats().warmup_latency.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_training_primes_without_promoting_or_swapping() {
+        let atomic_model = AtomicModel::new(create_test_model());
+
+        {
+            let mut training = atomic_model.training.lock();
+            training.import_parameters(vec![0.0, 1.0]).unwrap();
+        }
+
+        let sample = FeatureVector::new(vec![5.0]);
+        atomic_model.warmup_training(Some(&sample), 3).unwrap();
+
+        // Warming the training model shouldn't touch current or version
+        assert_eq!(atomic_model.get_version(), 1);
+        assert_eq!(atomic_model.get_stats().warmup_latency.count(), 1);
+
+        atomic_model.swap_models().unwrap();
+        let prediction = atomic_model.predict(&sample).await.unwrap();
+        assert!((prediction - 5.0).abs() < 1e-4); // y = 0 + 1*5
+    }
+
+    #[tokio::test]
+    async fn test_atomic_model_import_training_parameters_lands_on_swap() {
+        let atomic_model = AtomicModel::new(create_test_model());
+
+        atomic_model.import_training_parameters(vec![1.0, 2.0]).unwrap();
+        atomic_model.swap_models().unwrap();
+
+        let test_feature = FeatureVector::new(vec![5.0]);
+        // weights [1.0, 2.0] with a bias term: prediction = 1.0 + 2.0 * 5.0
+        let prediction = atomic_model.predict(&test_feature).await.unwrap();
+        assert!((prediction - 11.0).abs() < 1e-4, "expected ~11.0, got {}", prediction);
+    }
 }
\ No newline at end of file
@@ -1,19 +1,66 @@
 use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
 use std::collections::HashMap;
-use std::time::Duration;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{mpsc, oneshot, RwLock};
 
 use crate::traits::features::FeatureVector;
-use crate::traits::model::{Model, ModelError};
-use crate::server::model_server::{AtomicModel, ModelWrapper};
-use crate::server::continuous_learning::{TrainingBuffer, ContinuousLearningConfig};
+use crate::traits::model::{Classifier, IncrementalModel, Model, ModelError, ModelFactory, SerializationFormat};
+use crate::server::batching::{run_dispatcher, PredictRequest};
+use crate::server::model_server::{AtomicModel, IncrementalModelWrapper, ModelWrapper, VersionInfo};
+use crate::server::continuous_learning::{TrainingBuffer, ContinuousLearningConfig, DriftPolicy};
+use crate::server::drift::DriftMonitor;
+use crate::server::snapshot::SnapshotStore;
+use crate::server::swap_decision;
+use crate::server::training_history::{CycleReport, SwapReason, TrainingHistory};
+use crate::server::tuner::HyperparamTuner;
 
 /// Server for managing multiple models
+///
+/// Cheaply `Clone`: every field is an `Arc` (or, for `config`, a small plain struct),
+/// so cloning just hands out another handle to the same shared state -- this is how
+/// `start_continuous_learning`'s background task gets a handle back into `&self`
+/// methods like `retrain_cycle` without borrowing across a `'static` spawned future.
+#[derive(Clone)]
 pub struct ModelServer {
     /// Map of model name to atomic model instance
     models: Arc<RwLock<HashMap<String, Arc<dyn ModelWrapper>>>>,
     /// Map of model name to training data buffer
     training_buffers: Arc<RwLock<HashMap<String, TrainingBuffer>>>,
+    /// Map of model name to its prediction dispatcher channel
+    dispatch_senders: Arc<RwLock<HashMap<String, mpsc::Sender<PredictRequest>>>>,
+    /// Map of model name to its concept-drift monitor
+    drift_monitors: Arc<RwLock<HashMap<String, DriftMonitor>>>,
+    /// Map of model name to its hyperparameter-tuning factory and observed trials,
+    /// present only for models registered via `register_model_with_tuning`
+    tuners: Arc<RwLock<HashMap<String, (Arc<dyn ModelFactory>, HyperparamTuner)>>>,
+    /// Map of model name to its per-cycle training history and aggregates
+    training_history: Arc<RwLock<HashMap<String, TrainingHistory>>>,
+    /// Map of model name to its on-disk snapshot store, present only when
+    /// `config.snapshot_dir` is set
+    snapshots: Arc<RwLock<HashMap<String, SnapshotStore>>>,
+    /// Map of classifier name to its classifier instance and current version
+    ///
+    /// Kept as a separate registry from `models` rather than folded into
+    /// `ModelWrapper`/`AtomicModel`, since classification doesn't (yet) need
+    /// continuous-learning versioning, drift monitoring, or micro-batched dispatch --
+    /// just train-then-serve.
+    classifiers: Arc<RwLock<HashMap<String, (Box<dyn Classifier>, usize)>>>,
+    /// Map of anomaly-detector model name to its configured `z_threshold`
+    ///
+    /// Anomaly detectors are plain `Model`s (see `register_anomaly_detector`) so they
+    /// get the full continuous-learning treatment, but `predict` can only return a
+    /// score -- this is where `detect_anomaly` looks up the threshold that turns that
+    /// score into an `is_anomaly` flag.
+    anomaly_thresholds: Arc<RwLock<HashMap<String, f32>>>,
+    /// Map of model name to a type-erased handle for applying `IncrementalModel::update`,
+    /// present only for models registered via `register_model_with_incremental_updates`
+    ///
+    /// Kept as a separate registry rather than folded into `ModelWrapper`, since only
+    /// some models implement `IncrementalModel` and Rust has no stable way to
+    /// conditionally extend a single blanket `impl ModelWrapper for AtomicModel<M>`
+    /// based on an additional bound on `M` -- mirrors how `tuners`/`classifiers` are
+    /// kept separate from `models` for analogous reasons.
+    incremental_models: Arc<RwLock<HashMap<String, Arc<dyn IncrementalModelWrapper>>>>,
     /// Server configuration
     config: ContinuousLearningConfig,
     /// Is the server running?
@@ -26,54 +73,247 @@ impl ModelServer {
         Self {
             models: Arc::new(RwLock::new(HashMap::new())),
             training_buffers: Arc::new(RwLock::new(HashMap::new())),
+            dispatch_senders: Arc::new(RwLock::new(HashMap::new())),
+            drift_monitors: Arc::new(RwLock::new(HashMap::new())),
+            tuners: Arc::new(RwLock::new(HashMap::new())),
+            training_history: Arc::new(RwLock::new(HashMap::new())),
+            snapshots: Arc::new(RwLock::new(HashMap::new())),
+            classifiers: Arc::new(RwLock::new(HashMap::new())),
+            anomaly_thresholds: Arc::new(RwLock::new(HashMap::new())),
+            incremental_models: Arc::new(RwLock::new(HashMap::new())),
             config,
             running: Arc::new(AtomicBool::new(false)),
         }
     }
-    
+
     /// Create a new model server with default configuration
     pub fn default() -> Self {
         Self::new(ContinuousLearningConfig::default())
     }
-    
+
     /// Register a new model with the server
     pub async fn register_model<M: Model + Clone + Send + Sync + 'static>(
         &self,
         name: &str,
         model: M,
     ) -> Result<(), ModelError> {
+        self.build_model(name, model).await.map(|_| ())
+    }
+
+    /// Shared guts of model registration: builds the `AtomicModel`, wires up its
+    /// training buffer, drift monitor, prediction dispatcher, training history, and
+    /// snapshot store, and hands back the concrete `Arc<AtomicModel<M>>`.
+    ///
+    /// Returning the concrete type (rather than the immediately-erased
+    /// `Arc<dyn ModelWrapper>`) lets callers that need a type-specific trait object --
+    /// e.g. `register_model_with_incremental_updates`'s `Arc<dyn IncrementalModelWrapper>` --
+    /// coerce it themselves before the type is gone for good.
+    async fn build_model<M: Model + Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        model: M,
+    ) -> Result<Arc<AtomicModel<M>>, ModelError> {
         let mut models = self.models.write().await;
-        
+
         if models.contains_key(name) {
             return Err(ModelError::InvalidParameter(format!("Model '{}' already exists", name)));
         }
-        
+
         // Create atomic model container
-        let atomic_model = AtomicModel::new(model);
-        models.insert(name.to_string(), Arc::new(atomic_model));
-        
+        let atomic = Arc::new(AtomicModel::new(model).with_max_history(self.config.max_version_history));
+        let wrapper: Arc<dyn ModelWrapper> = Arc::clone(&atomic) as Arc<dyn ModelWrapper>;
+        models.insert(name.to_string(), Arc::clone(&wrapper));
+
         // Initialize training buffer
         let mut buffers = self.training_buffers.write().await;
-        buffers.insert(name.to_string(), TrainingBuffer::new());
-        
+        let buffer = TrainingBuffer::new()
+            .with_recency_weighting(self.config.recency_decay_rate, self.config.validation_assign_probability);
+        buffers.insert(name.to_string(), buffer);
+
+        // Initialize the concept-drift monitor
+        let mut drift_monitors = self.drift_monitors.write().await;
+        drift_monitors.insert(
+            name.to_string(),
+            DriftMonitor::new(self.config.drift_window_size, self.config.drift_bins),
+        );
+
+        // Spawn the micro-batching prediction dispatcher for this model
+        let (sender, receiver) = mpsc::channel(1024);
+        let max_batch_size = self.config.max_batch_size;
+        let max_batch_delay = Duration::from_micros(self.config.max_batch_delay_us);
+        tokio::spawn(run_dispatcher(wrapper, receiver, max_batch_size, max_batch_delay));
+
+        let mut dispatch_senders = self.dispatch_senders.write().await;
+        dispatch_senders.insert(name.to_string(), sender);
+
+        // Initialize this model's per-cycle training history
+        let mut training_history = self.training_history.write().await;
+        training_history.insert(name.to_string(), TrainingHistory::new(self.config.training_history_capacity));
+
+        // Wire up disk-backed snapshot persistence, if configured
+        if let Some(snapshot_dir) = &self.config.snapshot_dir {
+            let store = SnapshotStore::new(snapshot_dir.join(name), self.config.snapshot_format)
+                .with_max_snapshots(self.config.max_version_history);
+            let mut snapshots = self.snapshots.write().await;
+            snapshots.insert(name.to_string(), store);
+        }
+
+        Ok(atomic)
+    }
+
+    /// Register a new model along with an `IncrementalModel::update` path, so
+    /// individual examples added via `add_training_example`/`add_training_example_weighted`
+    /// are applied immediately as an online update (see `IncrementalModelWrapper`),
+    /// in addition to being buffered for the next full retrain cycle like any other
+    /// registered model
+    pub async fn register_model_with_incremental_updates<M: Model + IncrementalModel + Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        model: M,
+    ) -> Result<(), ModelError> {
+        let atomic = self.build_model(name, model).await?;
+        let mut incremental_models = self.incremental_models.write().await;
+        incremental_models.insert(name.to_string(), Arc::clone(&atomic) as Arc<dyn IncrementalModelWrapper>);
         Ok(())
     }
-    
+
+    /// Register a new model along with a `ModelFactory`, enabling Bayesian-optimization
+    /// hyperparameter tuning over `config.tuning_space` for this model
+    ///
+    /// A no-op beyond plain registration if `config.tuning_space` is unset; `factory`
+    /// is only retained when there's a search space to tune it over.
+    pub async fn register_model_with_tuning<M: Model + Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        model: M,
+        factory: Arc<dyn ModelFactory>,
+    ) -> Result<(), ModelError> {
+        self.register_model(name, model).await?;
+
+        if let Some(space) = self.config.tuning_space.clone() {
+            let mut tuners = self.tuners.write().await;
+            tuners.insert(name.to_string(), (factory, HyperparamTuner::new(space, self.config.tuning_cadence)));
+        }
+
+        Ok(())
+    }
+
     /// Unregister a model from the server
     pub async fn unregister_model(&self, name: &str) -> Result<(), ModelError> {
         let mut models = self.models.write().await;
         let mut buffers = self.training_buffers.write().await;
-        
-        if !models.contains_key(name) {
+        let mut dispatch_senders = self.dispatch_senders.write().await;
+        let mut drift_monitors = self.drift_monitors.write().await;
+        let mut tuners = self.tuners.write().await;
+        let mut training_history = self.training_history.write().await;
+        let mut snapshots = self.snapshots.write().await;
+        let mut classifiers = self.classifiers.write().await;
+        let mut anomaly_thresholds = self.anomaly_thresholds.write().await;
+        let mut incremental_models = self.incremental_models.write().await;
+
+        if !models.contains_key(name) && !classifiers.contains_key(name) {
             return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name)));
         }
-        
+
         models.remove(name);
         buffers.remove(name);
-        
+        // Dropping the sender lets the dispatcher loop exit once it drains any in-flight requests
+        dispatch_senders.remove(name);
+        drift_monitors.remove(name);
+        tuners.remove(name);
+        training_history.remove(name);
+        snapshots.remove(name);
+        classifiers.remove(name);
+        anomaly_thresholds.remove(name);
+        incremental_models.remove(name);
+
+        Ok(())
+    }
+
+    /// Register a new anomaly detector, storing its `z_threshold` alongside the plain
+    /// `Model` registration so `detect_anomaly` can turn future scores into an
+    /// `is_anomaly` flag without needing to know the model's concrete type
+    pub async fn register_anomaly_detector<M: Model + Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        detector: M,
+        z_threshold: f32,
+    ) -> Result<(), ModelError> {
+        self.register_model(name, detector).await?;
+        self.anomaly_thresholds.write().await.insert(name.to_string(), z_threshold);
         Ok(())
     }
+
+    /// Register a new classifier with the server
+    pub async fn register_classifier(&self, name: &str, classifier: Box<dyn Classifier>) -> Result<(), ModelError> {
+        let mut classifiers = self.classifiers.write().await;
+
+        if classifiers.contains_key(name) {
+            return Err(ModelError::InvalidParameter(format!("Model '{}' already exists", name)));
+        }
+
+        classifiers.insert(name.to_string(), (classifier, 1));
+        Ok(())
+    }
+
+    /// Train a registered classifier, bumping its served version on success
+    pub async fn train_classifier(&self, name: &str, features: &[FeatureVector], labels: &[usize]) -> Result<(), ModelError> {
+        let mut classifiers = self.classifiers.write().await;
+
+        match classifiers.get_mut(name) {
+            Some((classifier, version)) => {
+                classifier.train(features, labels)?;
+                *version += 1;
+                Ok(())
+            }
+            None => Err(ModelError::InvalidParameter(format!("Classifier '{}' not found", name))),
+        }
+    }
+
+    /// Classify a single feature vector, returning per-class scores alongside the
+    /// classifier's class names and current served version
+    pub async fn classify(&self, name: &str, feature: &FeatureVector) -> Result<(Vec<String>, Vec<f32>, usize), ModelError> {
+        let classifiers = self.classifiers.read().await;
+
+        match classifiers.get(name) {
+            Some((classifier, version)) => {
+                let scores = classifier.predict_scores(feature)?;
+                Ok((classifier.classes().to_vec(), scores, *version))
+            }
+            None => Err(ModelError::InvalidParameter(format!("Classifier '{}' not found", name))),
+        }
+    }
+
+    /// Classify multiple feature vectors, returning per-class scores for each alongside
+    /// the classifier's class names and current served version
+    pub async fn classify_batch(&self, name: &str, features: &[FeatureVector]) -> Result<(Vec<String>, Vec<Vec<f32>>, usize), ModelError> {
+        let classifiers = self.classifiers.read().await;
+
+        match classifiers.get(name) {
+            Some((classifier, version)) => {
+                let scores = classifier.predict_scores_batch(features)?;
+                Ok((classifier.classes().to_vec(), scores, *version))
+            }
+            None => Err(ModelError::InvalidParameter(format!("Classifier '{}' not found", name))),
+        }
+    }
     
+    /// Score a feature vector against a registered anomaly detector, returning its
+    /// anomaly score, whether it exceeds the detector's configured `z_threshold`, and
+    /// the model's current served version
+    pub async fn detect_anomaly(&self, name: &str, feature: &FeatureVector) -> Result<(f32, bool, usize), ModelError> {
+        let threshold = {
+            let thresholds = self.anomaly_thresholds.read().await;
+            *thresholds
+                .get(name)
+                .ok_or_else(|| ModelError::InvalidParameter(format!("Anomaly detector '{}' not found", name)))?
+        };
+
+        let score = self.predict(name, feature).await?;
+        let version = self.get_model(name).await?.get_version();
+        Ok((score, score.abs() > threshold, version))
+    }
+
     /// Get a reference to a model
     pub async fn get_model(&self, name: &str) -> Result<Arc<dyn ModelWrapper>, ModelError> {
         let models = self.models.read().await;
@@ -84,97 +324,449 @@ impl ModelServer {
         }
     }
     
+    /// Get a clone of a model's prediction dispatcher channel
+    async fn get_dispatch_sender(&self, name: &str) -> Result<mpsc::Sender<PredictRequest>, ModelError> {
+        let dispatch_senders = self.dispatch_senders.read().await;
+        match dispatch_senders.get(name) {
+            Some(sender) => Ok(sender.clone()),
+            None => Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+        }
+    }
+
     /// Make a prediction using a named model
+    ///
+    /// Pushes onto the model's micro-batching dispatcher and awaits the result, so
+    /// this prediction may be served alongside other concurrently-queued requests.
     pub async fn predict(&self, name: &str, feature: &FeatureVector) -> Result<f32, ModelError> {
-        let model = self.get_model(name).await?;
-        model.predict(feature).await
+        let sender = self.get_dispatch_sender(name).await?;
+
+        let (respond_to, response) = oneshot::channel();
+        sender
+            .send(PredictRequest { feature: feature.clone(), respond_to })
+            .await
+            .map_err(|_| ModelError::PredictionError("Prediction dispatcher is no longer running".to_string()))?;
+
+        let prediction = response
+            .await
+            .map_err(|_| ModelError::PredictionError("Prediction dispatcher dropped the request".to_string()))??;
+
+        if let Some(monitor) = self.drift_monitors.write().await.get_mut(name) {
+            monitor.record_prediction(prediction);
+        }
+
+        Ok(prediction)
     }
-    
+
     /// Make batch predictions using a named model
+    ///
+    /// Each feature is queued independently so the dispatcher can coalesce them (and
+    /// any concurrently-arriving single predictions) into one or more underlying
+    /// `ModelWrapper::predict_batch` calls.
     pub async fn predict_batch(&self, name: &str, features: &[FeatureVector]) -> Result<Vec<f32>, ModelError> {
-        let model = self.get_model(name).await?;
-        
-        // Using the ModelWrapper trait, we need to convert the batch prediction to individual predictions
-        let mut predictions = Vec::with_capacity(features.len());
+        let sender = self.get_dispatch_sender(name).await?;
+
+        let mut responses = Vec::with_capacity(features.len());
         for feature in features {
-            predictions.push(model.predict(feature).await?);
+            let (respond_to, response) = oneshot::channel();
+            sender
+                .send(PredictRequest { feature: feature.clone(), respond_to })
+                .await
+                .map_err(|_| ModelError::PredictionError("Prediction dispatcher is no longer running".to_string()))?;
+            responses.push(response);
         }
-        
+
+        let mut predictions = Vec::with_capacity(responses.len());
+        for response in responses {
+            let prediction = response
+                .await
+                .map_err(|_| ModelError::PredictionError("Prediction dispatcher dropped the request".to_string()))??;
+            predictions.push(prediction);
+        }
+
+        if let Some(monitor) = self.drift_monitors.write().await.get_mut(name) {
+            for &prediction in &predictions {
+                monitor.record_prediction(prediction);
+            }
+        }
+
         Ok(predictions)
     }
     
+    /// If `name` was registered via `register_model_with_incremental_updates`, apply
+    /// `feature`/`target` as an online update right away; a no-op for every other
+    /// model, since only opted-in models carry an `IncrementalModelWrapper` handle
+    ///
+    /// Failures are logged rather than propagated: the example has already been
+    /// durably buffered for the next full retrain cycle by the time this runs, so
+    /// surfacing an `Err` here (e.g. a transient "training in progress" conflict)
+    /// would wrongly suggest the whole call failed and invite the caller to retry,
+    /// double-buffering an example that was, in fact, already recorded.
+    async fn apply_incremental_update(&self, name: &str, feature: &FeatureVector, target: f32) {
+        let incremental_models = self.incremental_models.read().await;
+        if let Some(model) = incremental_models.get(name) {
+            if let Err(err) = model.update_incremental(std::slice::from_ref(feature), &[target]).await {
+                println!("Warning: failed to apply incremental update for model '{}': {}", name, err);
+            }
+        }
+    }
+
     /// Add a new training example (will be applied automatically by continuous learning)
+    ///
+    /// For models registered via `register_model_with_incremental_updates`, a
+    /// non-validation example is also applied as an immediate online update, on top
+    /// of being buffered for the next full retrain cycle like any other model.
     pub async fn add_training_example(
         &self,
         name: &str,
         feature: FeatureVector,
         target: f32,
         is_validation: bool,
+    ) -> Result<(), ModelError> {
+        if is_validation {
+            let mut buffers = self.training_buffers.write().await;
+            match buffers.get_mut(name) {
+                Some(buffer) => buffer.add(feature, target, is_validation),
+                None => return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+            }
+            return Ok(());
+        }
+
+        {
+            let mut buffers = self.training_buffers.write().await;
+            match buffers.get_mut(name) {
+                Some(buffer) => buffer.add(feature.clone(), target, is_validation),
+                None => return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+            }
+        }
+
+        self.apply_incremental_update(name, &feature, target).await;
+
+        Ok(())
+    }
+
+    /// Add a new training example with an explicit importance weight
+    ///
+    /// Useful for imbalanced streams, recency weighting, or confidence-weighted labels;
+    /// the weight only affects training samples (see `TrainingBuffer::add_weighted`).
+    /// As with `add_training_example`, a non-validation example is also applied as an
+    /// immediate online update for models registered via
+    /// `register_model_with_incremental_updates`
+    pub async fn add_training_example_weighted(
+        &self,
+        name: &str,
+        feature: FeatureVector,
+        target: f32,
+        weight: f32,
+        is_validation: bool,
+    ) -> Result<(), ModelError> {
+        if is_validation {
+            let mut buffers = self.training_buffers.write().await;
+            match buffers.get_mut(name) {
+                Some(buffer) => buffer.add_weighted(feature, target, weight, is_validation),
+                None => return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+            }
+            return Ok(());
+        }
+
+        {
+            let mut buffers = self.training_buffers.write().await;
+            match buffers.get_mut(name) {
+                Some(buffer) => buffer.add_weighted(feature.clone(), target, weight, is_validation),
+                None => return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+            }
+        }
+
+        self.apply_incremental_update(name, &feature, target).await;
+
+        Ok(())
+    }
+
+    /// Add a new training example, auto-assigning it to train or validation by the
+    /// server's configured `validation_assign_probability` instead of requiring the
+    /// caller to decide (see `ContinuousLearningConfig::with_recency_weighting`)
+    pub async fn add_training_example_auto(
+        &self,
+        name: &str,
+        feature: FeatureVector,
+        target: f32,
+        weight: f32,
     ) -> Result<(), ModelError> {
         let mut buffers = self.training_buffers.write().await;
-        
+
         match buffers.get_mut(name) {
             Some(buffer) => {
-                buffer.add(feature, target, is_validation);
+                buffer.add_auto(feature, target, weight);
                 Ok(())
             }
             None => Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
         }
     }
-    
+
+    /// Persist `version` to disk if `name` has a configured `SnapshotStore`; a no-op
+    /// when `config.snapshot_dir` is unset. Failures are logged rather than
+    /// propagated, since a missed snapshot shouldn't fail an otherwise-successful swap
+    async fn snapshot_version(&self, name: &str, model: &Arc<dyn ModelWrapper>, version: usize) {
+        let snapshots = self.snapshots.read().await;
+        if let Some(store) = snapshots.get(name) {
+            if let Err(err) = model.save_snapshot(store, version) {
+                println!("Warning: failed to persist snapshot for model '{}' version {}: {}", name, version, err);
+            }
+        }
+    }
+
+    /// Decide whether to promote a freshly trained candidate over the incumbent and,
+    /// if so, swap and snapshot it: KL-gated via `swap_decision::decide_with_kl_divergence`
+    /// when `config.use_kl_divergence` is set, otherwise a plain validation-threshold
+    /// check (swapping unconditionally, just flagging the regression in `SwapReason` and
+    /// a log line, matching the existing threshold-check semantics). Swaps unconditionally
+    /// when `val_features` is empty, since there's nothing to gate the decision on.
+    ///
+    /// Shared by `train_now` and the `start_continuous_learning` background task, so a
+    /// server driven purely by automatic continuous learning applies the exact same
+    /// swap policy as one driven by explicit `train_now` calls.
+    async fn decide_and_apply_swap(
+        &self,
+        model: &Arc<dyn ModelWrapper>,
+        name: &str,
+        val_features: &[FeatureVector],
+        val_targets: &[f32],
+        val_weights: Option<&[f32]>,
+    ) -> Result<(bool, Option<f32>, Option<f32>, SwapReason), ModelError> {
+        if val_features.is_empty() {
+            let new_version = model.swap_models()?;
+            self.snapshot_version(name, model, new_version).await;
+            return Ok((true, None, None, SwapReason::NoValidationData));
+        }
+
+        if self.config.use_kl_divergence {
+            // Compare the incumbent and the freshly trained candidate before swapping,
+            // so a re-fit that hasn't meaningfully moved the predictive distribution
+            // doesn't churn the served model
+            let (oe, ne) = model.compare_models(val_features, val_targets).await?;
+            let (old_predictions, new_predictions) = model.compare_predictions(val_features).await?;
+
+            let decision = swap_decision::decide_with_kl_divergence(
+                &old_predictions,
+                &new_predictions,
+                oe,
+                ne,
+                self.config.validation_threshold,
+                self.config.min_kl_divergence,
+            );
+
+            if decision.should_swap {
+                let new_version = model.swap_models()?;
+                model.record_validation_error(new_version, decision.new_error);
+                self.snapshot_version(name, model, new_version).await;
+                Ok((true, Some(decision.old_error), Some(decision.new_error), SwapReason::KlGateMet))
+            } else {
+                println!("Warning: New model ({}) doesn't clear the KL-divergence swap decision (old: {}, new: {}, kl: {:?})",
+                    name, decision.old_error, decision.new_error, decision.kl_divergence);
+                Ok((false, Some(decision.old_error), Some(decision.new_error), SwapReason::KlGateNotMet))
+            }
+        } else {
+            // Validate current model
+            let oe = model.validate_weighted(val_features, val_targets, val_weights).await?;
+
+            // First swap to the new model
+            let new_version = model.swap_models()?;
+
+            // Validate new model
+            let ne = model.validate_weighted(val_features, val_targets, val_weights).await?;
+            model.record_validation_error(new_version, ne);
+            self.snapshot_version(name, model, new_version).await;
+
+            let swap_reason = if ne > oe * (1.0 - self.config.validation_threshold) {
+                println!("Warning: New model ({}) doesn't improve validation error by threshold (old: {}, new: {})",
+                    name, oe, ne);
+                SwapReason::ThresholdNotMet
+            } else {
+                SwapReason::ThresholdMet
+            };
+            Ok((true, Some(oe), Some(ne), swap_reason))
+        }
+    }
+
     /// Force training for a model immediately
     pub async fn train_now(&self, name: &str) -> Result<(), ModelError> {
+        self.retrain_cycle(name).await
+    }
+
+    /// Run one retrain cycle for `name`: train on its buffered data, apply the
+    /// configured auto-swap policy via `decide_and_apply_swap`, run a hyperparameter-
+    /// tuning trial if due, and record a `CycleReport`.
+    ///
+    /// Shared by the manual `train_now` and the `start_continuous_learning` background
+    /// task, so a server driven purely by automatic continuous learning gets exactly
+    /// the same retrain policy as one driven by explicit `train_now` calls.
+    async fn retrain_cycle(&self, name: &str) -> Result<(), ModelError> {
+        let cycle_start = Instant::now();
+
         // Get the model
         let model = self.get_model(name).await?;
-        
+
         // Get the training buffer
         let mut buffers = self.training_buffers.write().await;
         let buffer = match buffers.get_mut(name) {
             Some(buffer) => buffer,
             None => return Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
         };
-        
+
         // Skip if no training data
         if buffer.features.is_empty() {
             return Ok(());
         }
-        
-        // Clone the training data
+
+        // Clone the training data, combining each sample's explicit weight with its
+        // recency-decay factor so a drifting stream's stale samples fade out on their own
         let features = buffer.features.clone();
         let targets = buffer.targets.clone();
-        
-        // Train the model
-        model.train(&features, &targets).await?;
-        
+        let (_, _, weights) = buffer.get_training_data_weighted();
+        let samples_trained = features.len();
+
+        // Train the model, honoring any per-sample weights collected via add_training_example_weighted
+        model.train_weighted(&features, &targets, Some(&weights)).await?;
+
         // Clear the training buffer
         buffer.clear_training();
-        
-        // If auto-swap is enabled, swap models
+
+        let train_set_size = features.len();
+        let val_set_size = buffer.val_features.len();
+        let mut old_error = None;
+        let mut new_error = None;
+        let mut swapped = false;
+        let mut swap_reason = SwapReason::AutoSwapDisabled;
+
+        // If auto-swap is enabled, decide whether to swap models
         if self.config.auto_swap {
-            // If validation data exists, validate before swapping
+            // Recency-decay weights for the validation-threshold check, so stale
+            // validation points don't keep vetoing a genuinely improved model; unused
+            // when `use_kl_divergence` is set, which compares raw predictions instead
+            let (_, _, val_weights) = buffer.get_validation_data_weighted();
+
+            let (s, oe, ne, reason) = self
+                .decide_and_apply_swap(&model, name, &buffer.val_features, &buffer.val_targets, Some(&val_weights))
+                .await?;
+            swapped = s;
+            old_error = oe;
+            new_error = ne;
+            swap_reason = reason;
+
+            // Snapshot a fresh drift reference distribution from predictions served so far
+            if let Some(monitor) = self.drift_monitors.write().await.get_mut(name) {
+                monitor.snapshot_reference();
+            }
+
+            // Bayesian-optimization hyperparameter tuning: on cadence, train a trial
+            // model from a proposed candidate and promote it if it beats the model
+            // version we just swapped in
             if !buffer.val_features.is_empty() {
-                // Validate current model
-                let old_error = model.validate(&buffer.val_features, &buffer.val_targets).await?;
-                
-                // First swap to the new model
-                model.swap_models()?;
-                
-                // Validate new model
-                let new_error = model.validate(&buffer.val_features, &buffer.val_targets).await?;
-                
-                // If new model is not better by threshold, log warning
-                if new_error > old_error * (1.0 - self.config.validation_threshold) {
-                    println!("Warning: New model ({}) doesn't improve validation error by threshold (old: {}, new: {})",
-                        name, old_error, new_error);
+                let mut tuners = self.tuners.write().await;
+                if let Some((factory, tuner)) = tuners.get_mut(name) {
+                    if tuner.should_trial() {
+                        self.run_tuning_trial(
+                            &model,
+                            name,
+                            factory.as_ref(),
+                            tuner,
+                            &features,
+                            &targets,
+                            &weights,
+                            &buffer.val_features,
+                            &buffer.val_targets,
+                        )
+                        .await;
+                    }
                 }
-            } else {
-                // No validation data, just swap
-                model.swap_models()?;
             }
         }
-        
+
+        if let Some(history) = self.training_history.write().await.get_mut(name) {
+            history.record(CycleReport {
+                timestamp: SystemTime::now(),
+                samples_trained,
+                train_set_size,
+                val_set_size,
+                old_error,
+                new_error,
+                swapped,
+                swap_reason,
+                duration: cycle_start.elapsed(),
+            });
+        }
+
         Ok(())
     }
+
+    /// Train and evaluate one hyperparameter-tuning candidate, promoting it over the
+    /// incumbent if its validation error is lower
+    async fn run_tuning_trial(
+        &self,
+        model: &Arc<dyn ModelWrapper>,
+        name: &str,
+        factory: &dyn ModelFactory,
+        tuner: &mut HyperparamTuner,
+        features: &[FeatureVector],
+        targets: &[f32],
+        weights: &[f32],
+        val_features: &[FeatureVector],
+        val_targets: &[f32],
+    ) {
+        let candidate = tuner.suggest();
+
+        let mut trial = match factory.create_with_params(&candidate) {
+            Ok(trial) => trial,
+            Err(err) => {
+                println!("Tuner for '{}' proposed an invalid candidate: {}", name, err);
+                tuner.discard_pending();
+                return;
+            }
+        };
+
+        if let Err(err) = trial.train_weighted(features, targets, Some(weights)) {
+            println!("Tuner for '{}' failed to train trial candidate: {}", name, err);
+            tuner.discard_pending();
+            return;
+        }
+
+        let trial_error = match trial.validate(val_features, val_targets) {
+            Ok(error) => error,
+            Err(err) => {
+                println!("Tuner for '{}' failed to validate trial candidate: {}", name, err);
+                tuner.discard_pending();
+                return;
+            }
+        };
+
+        tuner.record(trial_error);
+
+        let incumbent_error = match model.validate(val_features, val_targets).await {
+            Ok(error) => error,
+            Err(_) => return,
+        };
+
+        if trial_error >= incumbent_error {
+            return;
+        }
+
+        let exported = match trial.export_parameters() {
+            Ok(params) => params,
+            Err(_) => return,
+        };
+
+        if model.import_training_parameters(exported).is_err() {
+            return;
+        }
+
+        if let Ok(tuned_version) = model.swap_models() {
+            model.record_validation_error(tuned_version, trial_error);
+            self.snapshot_version(name, model, tuned_version).await;
+            println!(
+                "Tuner promoted model '{}' to version {} (validation error {} -> {})",
+                name, tuned_version, incumbent_error, trial_error
+            );
+        }
+    }
     
     /// Start the continuous learning background task
     pub async fn start_continuous_learning(&self) -> Result<(), ModelError> {
@@ -187,10 +779,14 @@ impl ModelServer {
         }
         
         self.running.store(true, Ordering::SeqCst);
-        
-        // Clone Arc references for the background task
+
+        // Clone a handle to the whole server -- cheap, since every field is an `Arc`
+        // (see the `Clone` impl doc comment) -- so the background task can call back
+        // into `&self` methods like `run_tuning_trial` and `decide_and_apply_swap`.
+        let server = self.clone();
         let models = Arc::clone(&self.models);
         let buffers = Arc::clone(&self.training_buffers);
+        let drift_monitors = Arc::clone(&self.drift_monitors);
         let config = self.config.clone();
         let running = Arc::clone(&self.running);
         
@@ -208,90 +804,65 @@ impl ModelServer {
                 
                 // Process each model
                 for name in model_names {
-                    // Check if model has enough training data
+                    // Check for concept drift and apply the configured policy
+                    let drift_exceeded = {
+                        let monitors = drift_monitors.read().await;
+                        monitors
+                            .get(&name)
+                            .and_then(|monitor| monitor.compute_drift())
+                            .map(|kl| kl > config.drift_threshold)
+                            .unwrap_or(false)
+                    };
+
+                    if drift_exceeded {
+                        match config.drift_policy {
+                            DriftPolicy::Ignore => {}
+                            DriftPolicy::MarkStale => {
+                                if let Some(model) = models.read().await.get(&name) {
+                                    model.mark_stale();
+                                }
+                                println!("Model {} marked stale: prediction distribution has drifted", name);
+                            }
+                            DriftPolicy::ForceRetrain => {
+                                println!("Model {} prediction distribution has drifted, forcing retrain", name);
+                            }
+                        }
+                    }
+
+                    // Check if model has enough training data (drift can force an
+                    // immediate retrain even below `min_samples`)
                     let should_train = {
                         let buffers = buffers.read().await;
                         match buffers.get(&name) {
-                            Some(buffer) => buffer.has_min_samples(config.min_samples),
+                            Some(buffer) => {
+                                buffer.has_min_samples(config.min_samples)
+                                    || (drift_exceeded && config.drift_policy == DriftPolicy::ForceRetrain)
+                            }
                             None => false,
                         }
                     };
-                    
+
                     if should_train {
-                        // Get the model
-                        let model = match models.read().await.get(&name) {
-                            Some(model) => Arc::clone(model),
+                        // Skip if already training
+                        let already_training = match models.read().await.get(&name) {
+                            Some(model) => model.is_training(),
                             None => continue,
                         };
-                        
-                        // Skip if already training
-                        if model.is_training() {
+                        if already_training {
                             continue;
                         }
-                        
-                        // Get training data
-                        let (features, targets) = {
-                            let mut buffers = buffers.write().await;
-                            let buffer = match buffers.get_mut(&name) {
-                                Some(buffer) => buffer,
-                                None => continue,
-                            };
-                            
-                            let features = buffer.features.clone();
-                            let targets = buffer.targets.clone();
-                            
-                            // Clear the buffer
-                            buffer.clear_training();
-                            
-                            (features, targets)
-                        };
-                        
-                        // Train the model
-                        if let Err(err) = model.train(&features, &targets).await {
+
+                        // Run the shared retrain cycle: train, apply the auto-swap/KL
+                        // policy, run a tuning trial if due, and record a CycleReport --
+                        // the exact same policy `train_now` applies
+                        if let Err(err) = server.retrain_cycle(&name).await {
                             println!("Error training model {}: {}", name, err);
-                            continue;
                         }
-                        
-                        // Get validation data
-                        let (val_features, val_targets) = {
-                            let buffers = buffers.read().await;
-                            let buffer = match buffers.get(&name) {
-                                Some(buffer) => buffer,
-                                None => continue,
-                            };
-                            
-                            (buffer.val_features.clone(), buffer.val_targets.clone())
-                        };
-                        
-                        // If auto-swap is enabled and validation data exists
-                        if config.auto_swap && !val_features.is_empty() {
-                            // Validate current model
-                            let old_error = match model.validate(&val_features, &val_targets).await {
-                                Ok(err) => err,
-                                Err(_) => continue,
-                            };
-                            
-                            // Swap models
-                            if let Err(_) = model.swap_models() {
-                                continue;
-                            }
-                            
-                            // Validate new model
-                            let new_error = match model.validate(&val_features, &val_targets).await {
-                                Ok(err) => err,
-                                Err(_) => continue,
-                            };
-                            
-                            // Log improvement
-                            println!("Model {} updated: Error changed from {} to {}", 
-                                name, old_error, new_error);
-                        } else if config.auto_swap {
-                            // No validation data, just swap
-                            if let Err(err) = model.swap_models() {
-                                println!("Error swapping model {}: {}", name, err);
-                            } else {
-                                println!("Model {} updated to version {}", 
-                                    name, model.get_version());
+
+                        // Snapshot a fresh drift reference from predictions served so far
+                        if config.auto_swap {
+                            if let Some(monitor) = drift_monitors.write().await.get_mut(&name) {
+                                monitor.snapshot_reference();
                             }
                         }
                     }
@@ -318,13 +889,85 @@ impl ModelServer {
         let model = self.get_model(name).await?;
         Ok(model.get_stats_formatted())
     }
+
+    /// List the versions currently retained in a model's rollback history
+    pub async fn list_versions(&self, name: &str) -> Result<Vec<VersionInfo>, ModelError> {
+        let model = self.get_model(name).await?;
+        Ok(model.list_versions())
+    }
+
+    /// Atomically revert a model's serving version to a previously-retained version.
+    /// Tries the in-memory rollback history first; if `target_version` has since been
+    /// evicted (or this is a fresh process after a restart), falls back to an on-disk
+    /// snapshot when `config.snapshot_dir` is configured for this model
+    pub async fn rollback(&self, name: &str, target_version: usize) -> Result<usize, ModelError> {
+        let model = self.get_model(name).await?;
+
+        match model.rollback(target_version) {
+            Ok(version) => Ok(version),
+            Err(err) => match self.snapshots.read().await.get(name) {
+                Some(store) => model.restore_snapshot(store, target_version),
+                None => Err(err),
+            },
+        }
+    }
+
+    /// List the versions currently retained on disk for a model, oldest first; empty
+    /// if `config.snapshot_dir` is unset or the model has no snapshots yet
+    pub async fn list_snapshots(&self, name: &str) -> Result<Vec<usize>, ModelError> {
+        self.get_model(name).await?;
+        Ok(self.snapshots.read().await.get(name).map(|store| store.versions()).unwrap_or_default())
+    }
+
+    /// Serialize a model's learned parameters directly to `path`, independent of the
+    /// bounded on-disk snapshot ring maintained automatically under `config.snapshot_dir`
+    pub async fn save_model(&self, name: &str, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        let model = self.get_model(name).await?;
+        model.save_to_path(path, format)
+    }
+
+    /// Deserialize `path` into a model's serving and training state
+    pub async fn load_model(&self, name: &str, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+        let model = self.get_model(name).await?;
+        model.load_from_path(path, format)
+    }
+
+    /// List a model's retained per-cycle training reports, oldest first
+    pub async fn training_reports(&self, name: &str) -> Result<Vec<CycleReport>, ModelError> {
+        let training_history = self.training_history.read().await;
+        match training_history.get(name) {
+            Some(history) => Ok(history.reports().iter().copied().collect()),
+            None => Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+        }
+    }
+
+    /// Format a compact table of a model's recent training cycles and aggregates
+    pub async fn training_summary(&self, name: &str) -> Result<String, ModelError> {
+        let training_history = self.training_history.read().await;
+        match training_history.get(name) {
+            Some(history) => Ok(history.summary()),
+            None => Err(ModelError::InvalidParameter(format!("Model '{}' not found", name))),
+        }
+    }
+
+    /// Aggregate every registered model's stats into a single Prometheus exposition
+    /// payload, ready to be served directly from a `/metrics` scrape endpoint
+    pub async fn metrics_snapshot(&self) -> String {
+        let models = self.models.read().await;
+        let mut out = String::new();
+        for (name, model) in models.iter() {
+            out.push_str(&model.get_stats_prometheus(name));
+        }
+        out
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::linears::LinearRegression;
-    
+    use crate::models::ridge::RidgeRegression;
+
     #[tokio::test]
     async fn test_model_server_register_unregister() {
         let server = ModelServer::default();
@@ -413,6 +1056,54 @@ mod tests {
         assert_eq!(buffer.targets.len(), 5);
     }
     
+    #[tokio::test]
+    async fn test_model_server_add_training_example_weighted() {
+        let server = ModelServer::default();
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..5 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            server.add_training_example_weighted(
+                "test_model",
+                feature,
+                (i * 2) as f32,
+                0.5,
+                false,
+            ).await.unwrap();
+        }
+
+        let buffers = server.training_buffers.read().await;
+        let buffer = buffers.get("test_model").unwrap();
+        assert_eq!(buffer.weights.len(), 5);
+        assert!(buffer.weights.iter().all(|&w| w == 0.5));
+    }
+
+    #[tokio::test]
+    async fn test_model_server_add_training_example_applies_incremental_update_immediately() {
+        let server = ModelServer::default();
+
+        let model = RidgeRegression::new(true, 0.01, 0.01, 1000).with_recursive_least_squares(1.0, 0.01);
+        server.register_model_with_incremental_updates("test_model", model).await.unwrap();
+
+        // y = 2*x + 1, fed one sample at a time via add_training_example
+        let xs = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        for x in xs {
+            let target = 2.0 * x + 1.0;
+            server.add_training_example("test_model", FeatureVector::new(vec![x]), target, false).await.unwrap();
+        }
+
+        // No train_now/swap was called -- the serving model should already reflect
+        // the online updates applied directly by add_training_example
+        let prediction = server.predict("test_model", &FeatureVector::new(vec![9.0])).await.unwrap();
+        assert!((prediction - 19.0).abs() < 1.0, "got {}", prediction);
+
+        // Still buffered for the next full retrain cycle, same as any other model
+        let buffers = server.training_buffers.read().await;
+        assert_eq!(buffers.get("test_model").unwrap().features.len(), 8);
+    }
+
     #[tokio::test]
     async fn test_model_server_train_now() {
         let server = ModelServer::default();
@@ -451,4 +1142,389 @@ mod tests {
         assert!(stats.contains("Predictions: 0"));
         assert!(stats.contains("Training runs: 0"));
     }
+
+    #[tokio::test]
+    async fn test_model_server_rollback() {
+        let server = ModelServer::default();
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+        let wrapper = server.get_model("test_model").await.unwrap();
+
+        // Version 1 is the initial registration; swap twice to create versions 2 and 3
+        wrapper.swap_models().unwrap();
+        wrapper.swap_models().unwrap();
+
+        let versions = server.list_versions("test_model").await.unwrap();
+        assert_eq!(versions.iter().map(|v| v.version).collect::<Vec<_>>(), vec![2, 3]);
+
+        let restored = server.rollback("test_model", 2).await.unwrap();
+        assert_eq!(restored, 2);
+
+        let stats = server.get_model_stats("test_model").await.unwrap();
+        assert!(stats.contains("Model v2"));
+
+        // Rolling back to a version that was never retained should fail
+        let result = server.rollback("test_model", 99).await;
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_model_server_metrics_snapshot() {
+        let server = ModelServer::default();
+
+        let model_a = LinearRegression::new(true, 0.01, 1000);
+        let model_b = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("model_a", model_a).await.unwrap();
+        server.register_model("model_b", model_b).await.unwrap();
+
+        let snapshot = server.metrics_snapshot().await;
+        assert!(snapshot.contains("continuum_model_version{model=\"model_a\"} 1"));
+        assert!(snapshot.contains("continuum_model_version{model=\"model_b\"} 1"));
+        assert!(snapshot.contains("continuum_prediction_total{model=\"model_a\"}"));
+    }
+
+    #[tokio::test]
+    async fn test_model_server_drift_monitor_tracks_predictions() {
+        let config = ContinuousLearningConfig::default().with_drift_detection(50, 5, 0.1, DriftPolicy::MarkStale);
+        let server = ModelServer::new(config);
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        server.register_model("test_model", model).await.unwrap();
+
+        // Feed a stable window of predictions, then snapshot it as the reference
+        for i in 0..50 {
+            server.predict("test_model", &FeatureVector::new(vec![(i % 5) as f32])).await.unwrap();
+        }
+        {
+            let mut monitors = server.drift_monitors.write().await;
+            monitors.get_mut("test_model").unwrap().snapshot_reference();
+        }
+
+        // Replaying the same distribution should show near-zero drift
+        for i in 0..50 {
+            server.predict("test_model", &FeatureVector::new(vec![(i % 5) as f32])).await.unwrap();
+        }
+        let stable_drift = {
+            let monitors = server.drift_monitors.read().await;
+            monitors.get("test_model").unwrap().compute_drift().unwrap()
+        };
+        assert!(stable_drift < 0.1);
+
+        // Shifting the live window far from the reference should show large drift
+        for _ in 0..50 {
+            server.predict("test_model", &FeatureVector::new(vec![1000.0])).await.unwrap();
+        }
+        let shifted_drift = {
+            let monitors = server.drift_monitors.read().await;
+            monitors.get("test_model").unwrap().compute_drift().unwrap()
+        };
+        assert!(shifted_drift > stable_drift);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_train_now_snapshots_drift_reference() {
+        let server = ModelServer::default();
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..5 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            server.add_training_example("test_model", feature, (i * 2) as f32, false).await.unwrap();
+        }
+        server.train_now("test_model").await.unwrap();
+
+        // No predictions were served before training, so the window (and thus the
+        // reference) is still empty -- snapshot_reference should have been a safe no-op
+        let monitors = server.drift_monitors.read().await;
+        assert!(monitors.get("test_model").unwrap().compute_drift().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_model_server_tuning_records_an_observation_per_trial() {
+        use crate::models::linears::LinearRegressionFactory;
+        use crate::server::tuner::{HyperparamRange, HyperparamSpace};
+
+        let space = HyperparamSpace::new(vec![
+            HyperparamRange::new(0.01, 0.2),
+            HyperparamRange::new(100.0, 2000.0),
+        ]);
+        let config = ContinuousLearningConfig::default().with_tuning(space, 1);
+        let server = ModelServer::new(config);
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        let factory: Arc<dyn ModelFactory> = Arc::new(LinearRegressionFactory::new(true));
+        server.register_model_with_tuning("test_model", model, factory).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            let is_validation = i % 3 == 0;
+            server.add_training_example("test_model", feature, (2 * i) as f32, is_validation).await.unwrap();
+        }
+
+        server.train_now("test_model").await.unwrap();
+
+        let tuners = server.tuners.read().await;
+        let (_, tuner) = tuners.get("test_model").unwrap();
+        assert_eq!(tuner.observation_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_without_tuning_space_skips_tuning() {
+        let server = ModelServer::default();
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            server.add_training_example("test_model", feature, (2 * i) as f32, i % 3 == 0).await.unwrap();
+        }
+        server.train_now("test_model").await.unwrap();
+
+        // No tuner was registered, since the server config has no tuning space
+        let tuners = server.tuners.read().await;
+        assert!(tuners.get("test_model").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_model_server_kl_divergence_gating_blocks_low_divergence_swap() {
+        let config = ContinuousLearningConfig::default().with_kl_divergence_gating(1000.0);
+        let server = ModelServer::new(config);
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            let is_validation = i % 3 == 0;
+            server.add_training_example("test_model", feature, (2 * i) as f32, is_validation).await.unwrap();
+        }
+
+        server.train_now("test_model").await.unwrap();
+
+        let model = server.get_model("test_model").await.unwrap();
+        assert_eq!(model.get_version(), 1, "an unreachable KL threshold should block the swap");
+    }
+
+    #[tokio::test]
+    async fn test_model_server_kl_divergence_gating_allows_diverged_swap() {
+        let config = ContinuousLearningConfig::default().with_kl_divergence_gating(0.0);
+        let server = ModelServer::new(config);
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            let is_validation = i % 3 == 0;
+            server.add_training_example("test_model", feature, (2 * i) as f32, is_validation).await.unwrap();
+        }
+
+        server.train_now("test_model").await.unwrap();
+
+        let model = server.get_model("test_model").await.unwrap();
+        assert_eq!(model.get_version(), 2, "a trained model with a near-zero KL threshold should swap in");
+    }
+
+    #[tokio::test]
+    async fn test_model_server_train_now_records_a_cycle_report() {
+        let server = ModelServer::default();
+
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+        model.import_parameters(vec![0.0, 1.0]).unwrap();
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            let is_validation = i % 3 == 0;
+            server.add_training_example("test_model", feature, (2 * i) as f32, is_validation).await.unwrap();
+        }
+
+        server.train_now("test_model").await.unwrap();
+
+        let reports = server.training_reports("test_model").await.unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].swapped);
+        assert_eq!(reports[0].swap_reason, SwapReason::ThresholdMet);
+        assert!(reports[0].old_error.is_some());
+        assert!(reports[0].new_error.is_some());
+
+        let summary = server.training_summary("test_model").await.unwrap();
+        assert!(summary.contains("cycles: 1"));
+        assert!(summary.contains("swaps: 1"));
+    }
+
+    #[tokio::test]
+    async fn test_model_server_train_now_records_auto_swap_disabled_reason() {
+        let config = ContinuousLearningConfig {
+            auto_swap: false,
+            ..ContinuousLearningConfig::default()
+        };
+        let server = ModelServer::new(config);
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+        server.add_training_example("test_model", FeatureVector::new(vec![1.0]), 2.0, false).await.unwrap();
+
+        server.train_now("test_model").await.unwrap();
+
+        let reports = server.training_reports("test_model").await.unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(!reports[0].swapped);
+        assert_eq!(reports[0].swap_reason, SwapReason::AutoSwapDisabled);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_add_training_example_auto_routes_by_probability() {
+        let config = ContinuousLearningConfig::default().with_recency_weighting(0.0, 1.0);
+        let server = ModelServer::new(config);
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..5 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            server.add_training_example_auto("test_model", feature, i as f32, 1.0).await.unwrap();
+        }
+
+        let buffers = server.training_buffers.read().await;
+        let buffer = buffers.get("test_model").unwrap();
+        assert_eq!(buffer.get_sizes(), (0, 5), "a validation_assign_probability of 1.0 should route every sample to validation");
+    }
+
+    #[tokio::test]
+    async fn test_model_server_recency_weighting_does_not_change_swap_outcome_when_disabled() {
+        // recency_decay_rate defaults to 0.0, so this should behave identically to the
+        // pre-existing unweighted threshold check
+        let server = ModelServer::default();
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        for i in 0..10 {
+            let feature = FeatureVector::new(vec![i as f32]);
+            let is_validation = i % 3 == 0;
+            server.add_training_example("test_model", feature, (2 * i) as f32, is_validation).await.unwrap();
+        }
+
+        server.train_now("test_model").await.unwrap();
+
+        let reports = server.training_reports("test_model").await.unwrap();
+        assert_eq!(reports[0].swap_reason, SwapReason::ThresholdMet);
+    }
+
+    fn temp_snapshot_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("continuum_server_snapshot_test_{}_{}", label, std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn test_model_server_persists_snapshot_on_swap() {
+        let dir = temp_snapshot_dir("on_swap");
+        let config = ContinuousLearningConfig::default().with_snapshot_dir(dir.clone(), SerializationFormat::Json);
+        let server = ModelServer::new(config);
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        server.add_training_example("test_model", FeatureVector::new(vec![1.0]), 2.0, false).await.unwrap();
+        server.train_now("test_model").await.unwrap();
+
+        let snapshots = server.list_snapshots("test_model").await.unwrap();
+        assert_eq!(snapshots, vec![2]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_rollback_falls_back_to_disk_snapshot_after_restart() {
+        let dir = temp_snapshot_dir("rollback_fallback");
+
+        // First "process": train and swap, persisting version 2's snapshot to disk
+        {
+            let config = ContinuousLearningConfig::default().with_snapshot_dir(dir.clone(), SerializationFormat::Json);
+            let server = ModelServer::new(config);
+            let model = LinearRegression::new(true, 0.01, 1000);
+            server.register_model("test_model", model).await.unwrap();
+            server.add_training_example("test_model", FeatureVector::new(vec![1.0]), 2.0, false).await.unwrap();
+            server.train_now("test_model").await.unwrap();
+        }
+
+        // A fresh "process" has an empty in-memory rollback history, so version 2 is
+        // only recoverable via the on-disk snapshot
+        let config = ContinuousLearningConfig::default().with_snapshot_dir(dir.clone(), SerializationFormat::Json);
+        let server = ModelServer::new(config);
+        let model = LinearRegression::new(true, 0.01, 1000);
+        server.register_model("test_model", model).await.unwrap();
+
+        let version = server.rollback("test_model", 2).await.unwrap();
+        assert_eq!(version, 2);
+
+        let result = server.rollback("test_model", 99).await;
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_train_and_classify() {
+        use crate::models::classification::LogisticRegression;
+
+        let server = ModelServer::default();
+        let classifier = LogisticRegression::new(vec!["neg".to_string(), "pos".to_string()], 0.1, 500, 0.0);
+        server.register_classifier("test_classifier", Box::new(classifier)).await.unwrap();
+
+        let features = vec![
+            FeatureVector::new(vec![-2.0]),
+            FeatureVector::new(vec![-1.0]),
+            FeatureVector::new(vec![1.0]),
+            FeatureVector::new(vec![2.0]),
+        ];
+        let labels = vec![0, 0, 1, 1];
+        server.train_classifier("test_classifier", &features, &labels).await.unwrap();
+
+        let (classes, scores, version) = server.classify("test_classifier", &FeatureVector::new(vec![3.0])).await.unwrap();
+        assert_eq!(classes, vec!["neg".to_string(), "pos".to_string()]);
+        assert!(scores[1] > scores[0]);
+        assert_eq!(version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_classify_unknown_classifier_errors() {
+        let server = ModelServer::default();
+        let result = server.classify("missing", &FeatureVector::new(vec![1.0])).await;
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_model_server_detect_anomaly_flags_outlier() {
+        use crate::models::anomaly::AnomalyDetector;
+
+        let server = ModelServer::default();
+        let detector = AnomalyDetector::new(3.0, 20);
+        server.register_anomaly_detector("test_detector", detector, 3.0).await.unwrap();
+
+        for _ in 0..30 {
+            server.add_training_example("test_detector", FeatureVector::new(vec![10.0]), 0.0, false).await.unwrap();
+        }
+        server.train_now("test_detector").await.unwrap();
+
+        let (score, is_anomaly, version) = server.detect_anomaly("test_detector", &FeatureVector::new(vec![10.0])).await.unwrap();
+        assert!(!is_anomaly, "a point matching the running mean shouldn't be anomalous, got score {}", score);
+        assert_eq!(version, 2);
+
+        let (score, is_anomaly, _) = server.detect_anomaly("test_detector", &FeatureVector::new(vec![1000.0])).await.unwrap();
+        assert!(is_anomaly, "a wildly out-of-range point should be anomalous, got score {}", score);
+    }
+
+    #[tokio::test]
+    async fn test_model_server_detect_anomaly_unknown_detector_errors() {
+        let server = ModelServer::default();
+        let result = server.detect_anomaly("missing", &FeatureVector::new(vec![1.0])).await;
+        assert!(matches!(result, Err(ModelError::InvalidParameter(_))));
+    }
 }
\ No newline at end of file
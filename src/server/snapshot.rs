@@ -0,0 +1,137 @@
+//! Bounded on-disk ring of versioned model snapshots
+//!
+//! `AtomicModel`'s rollback `history` (see `model_server.rs`) lives only in memory
+//! and is lost on restart. `SnapshotStore` gives `swap_models` an optional
+//! disk-backed twin of that ring, keyed by the same version numbers, so
+//! `ModelServer::rollback` can still recover a recently-retired version after a
+//! process restart -- important since continuous learning can silently degrade a
+//! model and operators need a fast revert without re-registering and re-training
+//! from scratch.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::traits::model::{Model, ModelError, SerializationFormat};
+
+/// Default number of on-disk snapshots retained per model, matching
+/// `model_server::DEFAULT_MAX_HISTORY`'s in-memory bound
+const DEFAULT_MAX_SNAPSHOTS: usize = 5;
+
+/// Manages a directory of `v{version}.snapshot` files for one model, pruning the
+/// oldest once more than `max_snapshots` are retained
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+    format: SerializationFormat,
+    max_snapshots: usize,
+}
+
+impl SnapshotStore {
+    /// Create a store rooted at `dir`, serializing snapshots in `format`
+    pub fn new(dir: impl Into<PathBuf>, format: SerializationFormat) -> Self {
+        Self { dir: dir.into(), format, max_snapshots: DEFAULT_MAX_SNAPSHOTS }
+    }
+
+    /// Configure how many versioned snapshots are retained before the oldest is pruned
+    pub fn with_max_snapshots(mut self, max_snapshots: usize) -> Self {
+        self.max_snapshots = max_snapshots.max(1);
+        self
+    }
+
+    fn path_for(&self, version: usize) -> PathBuf {
+        self.dir.join(format!("v{}.snapshot", version))
+    }
+
+    fn path_str(path: &std::path::Path) -> Result<&str, ModelError> {
+        path.to_str().ok_or_else(|| ModelError::InvalidParameter("snapshot path is not valid UTF-8".to_string()))
+    }
+
+    /// Serialize `model` as `version`'s snapshot, then prune older versions beyond
+    /// `max_snapshots`
+    pub fn save<M: Model>(&self, version: usize, model: &M) -> Result<(), ModelError> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path_for(version);
+        model.save_as(Self::path_str(&path)?, self.format)?;
+        self.prune()
+    }
+
+    /// Deserialize `version`'s snapshot into `model`, overwriting its current state
+    pub fn load<M: Model>(&self, version: usize, model: &mut M) -> Result<(), ModelError> {
+        let path = self.path_for(version);
+        model.load_from(Self::path_str(&path)?, self.format)
+    }
+
+    /// List every version currently retained on disk, oldest first
+    pub fn versions(&self) -> Vec<usize> {
+        let mut versions: Vec<usize> = fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                name.to_str()?.strip_prefix('v')?.strip_suffix(".snapshot")?.parse().ok()
+            })
+            .collect();
+        versions.sort_unstable();
+        versions
+    }
+
+    /// Remove every snapshot except the `max_snapshots` highest versions
+    fn prune(&self) -> Result<(), ModelError> {
+        let mut versions = self.versions();
+        while versions.len() > self.max_snapshots {
+            let oldest = versions.remove(0);
+            let _ = fs::remove_file(self.path_for(oldest));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::linears::LinearRegression;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("continuum_snapshot_test_{}_{}", label, std::process::id()))
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let dir = temp_dir("round_trip");
+        let store = SnapshotStore::new(&dir, SerializationFormat::Json);
+
+        let model = LinearRegression::new(true, 0.01, 1000);
+        store.save(1, &model).unwrap();
+        assert_eq!(store.versions(), vec![1]);
+
+        let mut restored = LinearRegression::new(true, 0.01, 1000);
+        store.load(1, &mut restored).unwrap();
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_prune_keeps_bounded_ring() {
+        let dir = temp_dir("prune");
+        let store = SnapshotStore::new(&dir, SerializationFormat::Json).with_max_snapshots(2);
+        let model = LinearRegression::new(true, 0.01, 1000);
+
+        for version in 1..=4 {
+            store.save(version, &model).unwrap();
+        }
+
+        assert_eq!(store.versions(), vec![3, 4]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_missing_version_errors() {
+        let dir = temp_dir("missing");
+        let store = SnapshotStore::new(&dir, SerializationFormat::Json);
+        let mut model = LinearRegression::new(true, 0.01, 1000);
+
+        assert!(store.load(1, &mut model).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,157 @@
+//! KL-divergence-aware swap decision for `ModelServer::decide_and_apply_swap`, shared by
+//! the manual `train_now` and the `start_continuous_learning` background task
+//!
+//! `ContinuousLearningConfig::use_kl_divergence` gates an extra check beyond the
+//! plain `validation_threshold` error-improvement test: both the incumbent's and the
+//! candidate's predictions over the same validation features are binned into
+//! histograms (the same bin -> Laplace-smooth -> normalize shape
+//! `crate::server::drift::DriftMonitor` uses for live drift detection) and compared
+//! via KL divergence. A candidate is only promoted when it both improves validation
+//! error past `validation_threshold` *and* its predictive distribution has diverged
+//! from the incumbent's by at least a configurable minimum KL, so a re-fit that barely
+//! moves the distribution doesn't churn the served model.
+
+use crate::server::metrics::kl_divergence;
+
+/// Small constant added to every histogram bin before normalizing, matching
+/// `crate::server::drift::DriftMonitor`'s smoothing
+const LAPLACE_EPSILON: f32 = 1e-3;
+/// Number of equal-width histogram bins used to compare predictive distributions
+const NUM_BINS: usize = 10;
+
+/// Outcome of comparing an incumbent and a candidate model ahead of a swap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwapDecision {
+    /// Whether the candidate should be promoted to serving
+    pub should_swap: bool,
+    /// Incumbent (currently-serving) model's validation error
+    pub old_error: f32,
+    /// Candidate (freshly trained) model's validation error
+    pub new_error: f32,
+    /// KL(P_new \| P_old) over binned predictions; `None` when the decision fell back
+    /// to a plain error-threshold check (`use_kl_divergence` disabled)
+    pub kl_divergence: Option<f32>,
+}
+
+/// Decide whether to promote `new_error` over `old_error` using only the relative
+/// error-improvement threshold
+pub fn decide(old_error: f32, new_error: f32, validation_threshold: f32) -> SwapDecision {
+    SwapDecision {
+        should_swap: new_error <= old_error * (1.0 - validation_threshold),
+        old_error,
+        new_error,
+        kl_divergence: None,
+    }
+}
+
+/// Decide whether to promote the candidate using both the error-improvement threshold
+/// and a minimum required KL divergence between the incumbent's and candidate's
+/// predictive distributions over the same validation features
+pub fn decide_with_kl_divergence(
+    old_predictions: &[f32],
+    new_predictions: &[f32],
+    old_error: f32,
+    new_error: f32,
+    validation_threshold: f32,
+    min_kl_divergence: f32,
+) -> SwapDecision {
+    let improved = new_error <= old_error * (1.0 - validation_threshold);
+    let divergence = predictive_kl_divergence(old_predictions, new_predictions);
+
+    SwapDecision {
+        should_swap: improved && divergence >= min_kl_divergence,
+        old_error,
+        new_error,
+        kl_divergence: Some(divergence),
+    }
+}
+
+/// KL(P_new \| P_old), each distribution a Laplace-smoothed histogram of the model's
+/// predictions over a value range shared by both
+fn predictive_kl_divergence(old_predictions: &[f32], new_predictions: &[f32]) -> f32 {
+    let range = value_range(old_predictions, new_predictions);
+    let old_probs = smoothed_probabilities(&bin_counts(old_predictions, range));
+    let new_probs = smoothed_probabilities(&bin_counts(new_predictions, range));
+    kl_divergence(&new_probs, &old_probs)
+}
+
+fn value_range(a: &[f32], b: &[f32]) -> (f32, f32) {
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+    for &v in a.iter().chain(b.iter()) {
+        min = min.min(v);
+        max = max.max(v);
+    }
+    if min >= max {
+        (min - 0.5, min + 0.5) // degenerate (all-identical) predictions still yield a usable range
+    } else {
+        (min, max)
+    }
+}
+
+fn bin_counts(values: &[f32], (min, max): (f32, f32)) -> Vec<f32> {
+    let mut counts = vec![0.0f32; NUM_BINS];
+    let range = (max - min).max(f32::EPSILON);
+
+    for &v in values {
+        let clamped = v.clamp(min, max);
+        let idx = (((clamped - min) / range) * NUM_BINS as f32) as usize;
+        counts[idx.min(NUM_BINS - 1)] += 1.0;
+    }
+
+    counts
+}
+
+fn smoothed_probabilities(counts: &[f32]) -> Vec<f32> {
+    let smoothed: Vec<f32> = counts.iter().map(|&c| c + LAPLACE_EPSILON).collect();
+    let total: f32 = smoothed.iter().sum();
+    smoothed.into_iter().map(|c| c / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decide_requires_error_improvement() {
+        let decision = decide(1.0, 0.96, 0.05);
+        assert!(!decision.should_swap, "1% improvement should not clear a 5% threshold");
+        assert!(decision.kl_divergence.is_none());
+
+        let decision = decide(1.0, 0.9, 0.05);
+        assert!(decision.should_swap);
+    }
+
+    #[test]
+    fn test_decide_with_kl_divergence_blocks_on_near_identical_distributions() {
+        // Error improves past threshold, but predictions are (almost) identical --
+        // KL divergence should be near zero and block the swap
+        let old_predictions: Vec<f32> = (0..100).map(|i| (i % 10) as f32).collect();
+        let new_predictions = old_predictions.clone();
+
+        let decision = decide_with_kl_divergence(&old_predictions, &new_predictions, 1.0, 0.9, 0.05, 0.1);
+        assert!(!decision.should_swap, "near-identical distributions should not clear the KL threshold");
+        assert!(decision.kl_divergence.unwrap() < 0.1);
+    }
+
+    #[test]
+    fn test_decide_with_kl_divergence_swaps_when_both_conditions_met() {
+        let old_predictions: Vec<f32> = (0..100).map(|i| (i % 10) as f32).collect();
+        let new_predictions: Vec<f32> = vec![9.0; 100];
+
+        let decision = decide_with_kl_divergence(&old_predictions, &new_predictions, 1.0, 0.9, 0.05, 0.1);
+        assert!(decision.should_swap);
+        assert!(decision.kl_divergence.unwrap() > 0.1);
+    }
+
+    #[test]
+    fn test_decide_with_kl_divergence_blocks_when_error_does_not_improve() {
+        // Distribution diverges a lot, but error doesn't actually improve
+        let old_predictions: Vec<f32> = (0..100).map(|i| (i % 10) as f32).collect();
+        let new_predictions: Vec<f32> = vec![9.0; 100];
+
+        let decision = decide_with_kl_divergence(&old_predictions, &new_predictions, 1.0, 1.2, 0.05, 0.1);
+        assert!(!decision.should_swap, "a regressed model should not swap regardless of KL divergence");
+        assert!(decision.kl_divergence.unwrap() > 0.1);
+    }
+}
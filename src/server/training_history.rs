@@ -0,0 +1,227 @@
+use std::collections::VecDeque;
+use std::time::{Duration, SystemTime};
+
+/// Why a retrain cycle did or didn't end in a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapReason {
+    /// Swapped: raw validation error cleared `validation_threshold`
+    ThresholdMet,
+    /// Did not swap: raw validation error did not clear `validation_threshold`
+    ThresholdNotMet,
+    /// Swapped: both the error-improvement threshold and the minimum KL-divergence
+    /// gate were satisfied (see `crate::server::swap_decision`)
+    KlGateMet,
+    /// Did not swap: the KL-divergence gate was not satisfied
+    KlGateNotMet,
+    /// Swapped unconditionally: no validation data was available to gate the decision
+    NoValidationData,
+    /// Did not swap: `ContinuousLearningConfig::auto_swap` is disabled
+    AutoSwapDisabled,
+}
+
+/// Record of a single retrain cycle, whether triggered manually via `ModelServer::train_now`
+/// or automatically by the `start_continuous_learning` background task
+#[derive(Debug, Clone, Copy)]
+pub struct CycleReport {
+    /// When this cycle completed
+    pub timestamp: SystemTime,
+    /// Number of training samples the model was trained on this cycle
+    pub samples_trained: usize,
+    /// Training-set size at the time of this cycle
+    pub train_set_size: usize,
+    /// Validation-set size at the time of this cycle
+    pub val_set_size: usize,
+    /// Incumbent (previously-serving) model's validation error, if validation data
+    /// was available
+    pub old_error: Option<f32>,
+    /// Candidate (freshly trained) model's validation error, if validation data was
+    /// available
+    pub new_error: Option<f32>,
+    /// Whether the candidate was swapped in to serving
+    pub swapped: bool,
+    /// Why the swap did or didn't happen
+    pub swap_reason: SwapReason,
+    /// Wall-clock time spent on this cycle (training plus any validation/swap work)
+    pub duration: Duration,
+}
+
+/// Bounded history of per-cycle training reports, plus running aggregates, for one
+/// model's continuous-learning loop
+#[derive(Debug)]
+pub struct TrainingHistory {
+    /// Most recent reports, oldest first, capped at `capacity`
+    reports: VecDeque<CycleReport>,
+    /// Maximum number of reports retained
+    capacity: usize,
+    /// Lowest validation error observed across all accepted swaps
+    best_validation_loss: Option<f32>,
+    /// Total number of cycles that ended in a swap
+    total_swaps: usize,
+    /// Running sum of `old_error - new_error` across accepted swaps, for
+    /// `mean_improvement_per_swap`
+    total_improvement: f32,
+}
+
+impl TrainingHistory {
+    /// Create a new, empty history retaining at most `capacity` recent reports
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            reports: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+            best_validation_loss: None,
+            total_swaps: 0,
+            total_improvement: 0.0,
+        }
+    }
+
+    /// Append a cycle report, updating running aggregates and evicting the oldest
+    /// report once `capacity` is exceeded
+    pub fn record(&mut self, report: CycleReport) {
+        if report.swapped {
+            self.total_swaps += 1;
+
+            if let Some(new_error) = report.new_error {
+                self.best_validation_loss = Some(match self.best_validation_loss {
+                    Some(best) => best.min(new_error),
+                    None => new_error,
+                });
+            }
+
+            if let (Some(old_error), Some(new_error)) = (report.old_error, report.new_error) {
+                self.total_improvement += old_error - new_error;
+            }
+        }
+
+        self.reports.push_back(report);
+        while self.reports.len() > self.capacity {
+            self.reports.pop_front();
+        }
+    }
+
+    /// Most recent reports retained, oldest first
+    pub fn reports(&self) -> &VecDeque<CycleReport> {
+        &self.reports
+    }
+
+    /// Lowest validation error observed across all accepted swaps, or `None` if no
+    /// swap carrying a validation error has happened yet
+    pub fn best_validation_loss(&self) -> Option<f32> {
+        self.best_validation_loss
+    }
+
+    /// Total number of cycles that ended in a swap
+    pub fn total_swaps(&self) -> usize {
+        self.total_swaps
+    }
+
+    /// Mean `old_error - new_error` across accepted swaps, or `None` if there haven't
+    /// been any
+    pub fn mean_improvement_per_swap(&self) -> Option<f32> {
+        if self.total_swaps == 0 {
+            None
+        } else {
+            Some(self.total_improvement / self.total_swaps as f32)
+        }
+    }
+
+    /// Format a compact table of the retained reports plus the running aggregates
+    pub fn summary(&self) -> String {
+        let mut out = format!(
+            "Training history | cycles: {} | swaps: {} | best val loss: {} | mean improvement/swap: {}\n",
+            self.reports.len(),
+            self.total_swaps,
+            format_opt(self.best_validation_loss),
+            format_opt(self.mean_improvement_per_swap()),
+        );
+
+        for report in self.reports.iter() {
+            out.push_str(&format!(
+                "  train={} val={} old_err={} new_err={} swapped={} reason={:?} duration={}ms\n",
+                report.train_set_size,
+                report.val_set_size,
+                format_opt(report.old_error),
+                format_opt(report.new_error),
+                report.swapped,
+                report.swap_reason,
+                report.duration.as_millis(),
+            ));
+        }
+
+        out
+    }
+}
+
+fn format_opt(value: Option<f32>) -> String {
+    value.map(|v| format!("{:.4}", v)).unwrap_or_else(|| "n/a".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(old_error: Option<f32>, new_error: Option<f32>, swapped: bool, reason: SwapReason) -> CycleReport {
+        CycleReport {
+            timestamp: SystemTime::now(),
+            samples_trained: 10,
+            train_set_size: 10,
+            val_set_size: 3,
+            old_error,
+            new_error,
+            swapped,
+            swap_reason: reason,
+            duration: Duration::from_millis(5),
+        }
+    }
+
+    #[test]
+    fn test_training_history_bounded_by_capacity() {
+        let mut history = TrainingHistory::new(2);
+        for _ in 0..5 {
+            history.record(report(Some(1.0), Some(0.9), true, SwapReason::ThresholdMet));
+        }
+        assert_eq!(history.reports().len(), 2);
+    }
+
+    #[test]
+    fn test_training_history_tracks_best_validation_loss() {
+        let mut history = TrainingHistory::new(10);
+        history.record(report(Some(1.0), Some(0.8), true, SwapReason::ThresholdMet));
+        history.record(report(Some(0.8), Some(0.5), true, SwapReason::ThresholdMet));
+        history.record(report(Some(0.5), Some(0.6), true, SwapReason::ThresholdMet));
+
+        assert_eq!(history.best_validation_loss(), Some(0.5));
+    }
+
+    #[test]
+    fn test_training_history_ignores_non_swap_cycles_for_aggregates() {
+        let mut history = TrainingHistory::new(10);
+        history.record(report(Some(1.0), Some(1.2), false, SwapReason::ThresholdNotMet));
+        history.record(report(None, None, false, SwapReason::AutoSwapDisabled));
+
+        assert_eq!(history.total_swaps(), 0);
+        assert_eq!(history.best_validation_loss(), None);
+        assert_eq!(history.mean_improvement_per_swap(), None);
+    }
+
+    #[test]
+    fn test_training_history_mean_improvement_per_swap() {
+        let mut history = TrainingHistory::new(10);
+        history.record(report(Some(1.0), Some(0.8), true, SwapReason::ThresholdMet)); // +0.2
+        history.record(report(Some(0.8), Some(0.6), true, SwapReason::ThresholdMet)); // +0.2
+        history.record(report(Some(0.6), Some(0.7), false, SwapReason::ThresholdNotMet)); // ignored
+
+        let mean = history.mean_improvement_per_swap().unwrap();
+        assert!((mean - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_training_history_summary_contains_key_fields() {
+        let mut history = TrainingHistory::new(10);
+        history.record(report(Some(1.0), Some(0.8), true, SwapReason::ThresholdMet));
+
+        let summary = history.summary();
+        assert!(summary.contains("cycles: 1"));
+        assert!(summary.contains("swaps: 1"));
+        assert!(summary.contains("ThresholdMet"));
+    }
+}
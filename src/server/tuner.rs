@@ -0,0 +1,351 @@
+//! Bayesian-optimization hyperparameter tuner for the continuous-learning loop
+//!
+//! Treats each retrain cycle's validation error -- whether from a manual `train_now`
+//! call or an automatic `start_continuous_learning` cycle -- as a black-box objective over
+//! a user-declared hyperparameter search space (see `ModelServer::register_model_with_tuning`
+//! and `ContinuousLearningConfig::with_tuning`). Observations are fit with a cheap
+//! kernel-weighted surrogate (a Nadaraya-Watson estimator over normalized params,
+//! standing in for a full Gaussian process) and the next candidate is chosen by
+//! maximizing Expected Improvement over randomly-drawn points.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Number of purely-random observations collected before trusting the surrogate
+const RANDOM_SEED_ROUNDS: usize = 4;
+/// Random candidates drawn per tuning tick when proposing by Expected Improvement
+const EI_CANDIDATE_POOL: usize = 32;
+/// RBF kernel bandwidth (in normalized [0, 1] space) used by the surrogate
+const KERNEL_BANDWIDTH: f32 = 0.25;
+
+/// One tunable hyperparameter's inclusive search range, in the model's native units
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HyperparamRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl HyperparamRange {
+    /// Create a new search range
+    pub fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    fn span(&self) -> f32 {
+        self.max - self.min
+    }
+}
+
+/// Declared search space: one range per hyperparameter dimension, in the order
+/// expected by the model's `ModelFactory::create_with_params`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperparamSpace {
+    ranges: Vec<HyperparamRange>,
+}
+
+impl HyperparamSpace {
+    /// Create a new search space from per-dimension ranges
+    pub fn new(ranges: Vec<HyperparamRange>) -> Self {
+        Self { ranges }
+    }
+
+    fn dims(&self) -> usize {
+        self.ranges.len()
+    }
+
+    fn normalize(&self, params: &[f32]) -> Vec<f32> {
+        params
+            .iter()
+            .zip(&self.ranges)
+            .map(|(p, r)| if r.span().abs() < f32::EPSILON { 0.0 } else { (p - r.min) / r.span() })
+            .collect()
+    }
+
+    fn denormalize(&self, normalized: &[f32]) -> Vec<f32> {
+        normalized.iter().zip(&self.ranges).map(|(n, r)| r.min + n * r.span()).collect()
+    }
+
+    fn random_normalized(&self, rng: &mut impl Rng) -> Vec<f32> {
+        (0..self.dims()).map(|_| rng.gen_range(0.0..1.0)).collect()
+    }
+}
+
+/// One observed `(hyperparams, validation_error)` pair, stored in normalized space
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Observation {
+    normalized_params: Vec<f32>,
+    validation_error: f32,
+}
+
+/// Per-model Bayesian-optimization tuner
+///
+/// Maintains observations in a flat list so it round-trips through serde (and can be
+/// persisted/restored alongside the rest of a model's state, letting tuning resume
+/// across restarts), and proposes the next candidate to try via Expected Improvement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperparamTuner {
+    space: HyperparamSpace,
+    observations: Vec<Observation>,
+    /// The normalized candidate most recently suggested, awaiting an observation
+    pending: Option<Vec<f32>>,
+    /// Tuning cadence: attempt a trial every `cadence` calls to `should_trial`
+    cadence: usize,
+    /// Calls to `should_trial` since the last attempted trial
+    ticks_since_trial: usize,
+}
+
+impl HyperparamTuner {
+    /// Create a new tuner over `space`, attempting a trial every `cadence` training cycles
+    pub fn new(space: HyperparamSpace, cadence: usize) -> Self {
+        Self {
+            space,
+            observations: Vec::new(),
+            pending: None,
+            cadence: cadence.max(1),
+            ticks_since_trial: 0,
+        }
+    }
+
+    /// Number of observations recorded so far
+    pub fn observation_count(&self) -> usize {
+        self.observations.len()
+    }
+
+    /// Best validation error observed so far, if any
+    pub fn best_error(&self) -> Option<f32> {
+        self.observations.iter().map(|obs| obs.validation_error).fold(None, |best, error| {
+            Some(best.map_or(error, |b: f32| b.min(error)))
+        })
+    }
+
+    /// Called once per training cycle; returns `true` when a tuning trial should be
+    /// attempted this cycle, resetting the internal cadence counter
+    pub fn should_trial(&mut self) -> bool {
+        self.ticks_since_trial += 1;
+        if self.ticks_since_trial >= self.cadence {
+            self.ticks_since_trial = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Propose the next candidate to evaluate, in the model's native hyperparameter units
+    pub fn suggest(&mut self) -> Vec<f32> {
+        let mut rng = rand::thread_rng();
+        let normalized = if self.observations.len() < RANDOM_SEED_ROUNDS {
+            self.space.random_normalized(&mut rng)
+        } else {
+            self.propose_by_expected_improvement(&mut rng)
+        };
+        let candidate = self.space.denormalize(&normalized);
+        self.pending = Some(normalized);
+        candidate
+    }
+
+    /// Record the validation error observed for the candidate returned by the most
+    /// recent call to `suggest`; a no-op if there's no pending candidate
+    pub fn record(&mut self, validation_error: f32) {
+        if let Some(normalized_params) = self.pending.take() {
+            self.observations.push(Observation { normalized_params, validation_error });
+        }
+    }
+
+    /// Discard the pending candidate without recording an observation, e.g. because
+    /// the trial model failed to train or validate
+    pub fn discard_pending(&mut self) {
+        self.pending = None;
+    }
+
+    /// Kernel-weighted mean and standard deviation of validation error at `point`,
+    /// standing in for a Gaussian process posterior (a Nadaraya-Watson estimator
+    /// with an RBF kernel); variance is inflated where nearby observations are sparse
+    /// so Expected Improvement keeps exploring
+    fn surrogate(&self, point: &[f32]) -> (f32, f32) {
+        let weights: Vec<f32> = self
+            .observations
+            .iter()
+            .map(|obs| {
+                let sq_dist: f32 =
+                    point.iter().zip(&obs.normalized_params).map(|(a, b)| (a - b).powi(2)).sum();
+                (-sq_dist / (2.0 * KERNEL_BANDWIDTH * KERNEL_BANDWIDTH)).exp()
+            })
+            .collect();
+
+        let weight_sum: f32 = weights.iter().sum();
+        let n = self.observations.len() as f32;
+
+        if weight_sum < 1e-6 {
+            let mean = self.observations.iter().map(|obs| obs.validation_error).sum::<f32>() / n;
+            return (mean, 1.0);
+        }
+
+        let mean =
+            weights.iter().zip(&self.observations).map(|(w, obs)| w * obs.validation_error).sum::<f32>()
+                / weight_sum;
+        let variance = weights
+            .iter()
+            .zip(&self.observations)
+            .map(|(w, obs)| w * (obs.validation_error - mean).powi(2))
+            .sum::<f32>()
+            / weight_sum;
+
+        let sparsity_penalty = (1.0 - weight_sum / n).max(0.0);
+        (mean, variance.sqrt().max(1e-3) + sparsity_penalty)
+    }
+
+    fn propose_by_expected_improvement(&self, rng: &mut impl Rng) -> Vec<f32> {
+        let f_best = self.best_error().unwrap_or(f32::MAX);
+        let mut best_candidate = self.space.random_normalized(rng);
+        let mut best_ei = f32::MIN;
+
+        for _ in 0..EI_CANDIDATE_POOL {
+            let candidate = self.space.random_normalized(rng);
+            let ei = expected_improvement(self.surrogate(&candidate), f_best);
+            if ei > best_ei {
+                best_ei = ei;
+                best_candidate = candidate;
+            }
+        }
+
+        best_candidate
+    }
+}
+
+/// Expected Improvement: `(f_best - mu)*CDF(z) + sigma*PDF(z)`, `z = (f_best - mu)/sigma`
+fn expected_improvement((mean, std_dev): (f32, f32), f_best: f32) -> f32 {
+    if std_dev < 1e-6 {
+        return (f_best - mean).max(0.0);
+    }
+    let z = (f_best - mean) / std_dev;
+    (f_best - mean) * standard_normal_cdf(z) + std_dev * standard_normal_pdf(z)
+}
+
+fn standard_normal_pdf(z: f32) -> f32 {
+    (-0.5 * z * z).exp() / (2.0 * std::f32::consts::PI).sqrt()
+}
+
+fn standard_normal_cdf(z: f32) -> f32 {
+    0.5 * (1.0 + erf(z / std::f32::consts::SQRT_2))
+}
+
+/// Abramowitz-Stegun approximation of the error function (max error ~1.5e-7)
+fn erf(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    sign * y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_space(dims: usize) -> HyperparamSpace {
+        HyperparamSpace::new((0..dims).map(|_| HyperparamRange::new(0.0, 1.0)).collect())
+    }
+
+    #[test]
+    fn test_hyperparam_space_normalize_denormalize_roundtrip() {
+        let space = HyperparamSpace::new(vec![HyperparamRange::new(0.001, 1.0), HyperparamRange::new(10.0, 1000.0)]);
+        let params = vec![0.5, 500.0];
+
+        let normalized = space.normalize(&params);
+        let restored = space.denormalize(&normalized);
+
+        for (original, restored) in params.iter().zip(restored.iter()) {
+            assert!((original - restored).abs() < 1e-3, "expected {} got {}", original, restored);
+        }
+    }
+
+    #[test]
+    fn test_tuner_records_one_observation_per_seed_round() {
+        let mut tuner = HyperparamTuner::new(unit_space(2), 1);
+
+        for _ in 0..RANDOM_SEED_ROUNDS {
+            assert_eq!(tuner.observation_count(), tuner.observation_count());
+            let _candidate = tuner.suggest();
+            tuner.record(1.0);
+        }
+
+        assert_eq!(tuner.observation_count(), RANDOM_SEED_ROUNDS);
+    }
+
+    #[test]
+    fn test_tuner_record_without_suggest_is_noop() {
+        let mut tuner = HyperparamTuner::new(unit_space(1), 1);
+        tuner.record(0.5);
+        assert_eq!(tuner.observation_count(), 0);
+    }
+
+    #[test]
+    fn test_tuner_discard_pending_drops_candidate_without_observation() {
+        let mut tuner = HyperparamTuner::new(unit_space(1), 1);
+        let _candidate = tuner.suggest();
+        tuner.discard_pending();
+        tuner.record(0.5);
+        assert_eq!(tuner.observation_count(), 0);
+    }
+
+    #[test]
+    fn test_should_trial_only_fires_every_cadence_calls() {
+        let mut tuner = HyperparamTuner::new(unit_space(1), 3);
+
+        assert!(!tuner.should_trial());
+        assert!(!tuner.should_trial());
+        assert!(tuner.should_trial());
+        assert!(!tuner.should_trial());
+    }
+
+    #[test]
+    fn test_best_error_tracks_minimum_observed() {
+        let mut tuner = HyperparamTuner::new(unit_space(1), 1);
+        assert_eq!(tuner.best_error(), None);
+
+        tuner.suggest();
+        tuner.record(0.8);
+        tuner.suggest();
+        tuner.record(0.3);
+        tuner.suggest();
+        tuner.record(0.5);
+
+        assert_eq!(tuner.best_error(), Some(0.3));
+    }
+
+    #[test]
+    fn test_expected_improvement_is_zero_far_above_incumbent_with_no_uncertainty() {
+        // A surrogate confident (sigma ~ 0) that the candidate is worse than f_best
+        // should report no expected improvement
+        let ei = expected_improvement((2.0, 1e-7), 1.0);
+        assert_eq!(ei, 0.0);
+    }
+
+    #[test]
+    fn test_expected_improvement_favors_lower_predicted_mean() {
+        let better = expected_improvement((0.2, 0.1), 1.0);
+        let worse = expected_improvement((0.9, 0.1), 1.0);
+        assert!(better > worse);
+    }
+
+    #[test]
+    fn test_surrogate_converges_near_dense_observations() {
+        let mut tuner = HyperparamTuner::new(unit_space(1), 1);
+        for _ in 0..20 {
+            tuner.suggest();
+            tuner.pending = Some(vec![0.5]);
+            tuner.record(0.42);
+        }
+
+        let (mean, _std_dev) = tuner.surrogate(&[0.5]);
+        assert!((mean - 0.42).abs() < 1e-3, "expected mean close to 0.42, got {}", mean);
+    }
+}
@@ -1,4 +1,8 @@
 use ndarray::Array1;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::traits::model::ModelError;
 
 #[derive(Clone, Debug)]
 pub struct FeatureVector{
@@ -18,6 +22,81 @@ impl FeatureVector {
     pub fn as_array(&self) -> &Array1<f32>{
         &self.values
     }
+
+    /// Parse a LIBSVM-format sparse file (`<label> <index>:<value> ...`, 1-based
+    /// indices) into dense feature vectors and their labels
+    ///
+    /// Missing indices are filled with zero so every vector comes out the same
+    /// width: the maximum index seen across the whole file.
+    pub fn from_libsvm_file(path: &str) -> Result<(Vec<FeatureVector>, Vec<f32>), ModelError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        let mut labels = Vec::new();
+        let mut sparse_rows: Vec<Vec<(usize, f32)>> = Vec::new();
+        let mut max_index = 0usize;
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let label_str = fields.next().ok_or_else(|| {
+                ModelError::SerializationError(format!("libsvm line {}: missing label", line_number + 1))
+            })?;
+            let label: f32 = label_str.parse().map_err(|e| {
+                ModelError::SerializationError(format!(
+                    "libsvm line {}: invalid label '{}': {}", line_number + 1, label_str, e
+                ))
+            })?;
+
+            let mut row = Vec::new();
+            for field in fields {
+                let (index_str, value_str) = field.split_once(':').ok_or_else(|| {
+                    ModelError::SerializationError(format!(
+                        "libsvm line {}: malformed feature '{}', expected index:value", line_number + 1, field
+                    ))
+                })?;
+                let index: usize = index_str.parse().map_err(|e| {
+                    ModelError::SerializationError(format!(
+                        "libsvm line {}: invalid feature index '{}': {}", line_number + 1, index_str, e
+                    ))
+                })?;
+                if index == 0 {
+                    return Err(ModelError::SerializationError(format!(
+                        "libsvm line {}: feature indices are 1-based, got 0", line_number + 1
+                    )));
+                }
+                let value: f32 = value_str.parse().map_err(|e| {
+                    ModelError::SerializationError(format!(
+                        "libsvm line {}: invalid feature value '{}': {}", line_number + 1, value_str, e
+                    ))
+                })?;
+
+                max_index = max_index.max(index);
+                row.push((index, value));
+            }
+
+            labels.push(label);
+            sparse_rows.push(row);
+        }
+
+        let features = sparse_rows
+            .into_iter()
+            .map(|row| {
+                let mut dense = vec![0.0f32; max_index];
+                for (index, value) in row {
+                    dense[index - 1] = value;
+                }
+                FeatureVector::new(dense)
+            })
+            .collect();
+
+        Ok((features, labels))
+    }
 }
 
 
@@ -33,4 +112,63 @@ mod tests {
         assert_eq!(vec.as_array()[1], 2.0);
         assert_eq!(vec.as_array()[2], 3.0);
     }
+
+    fn write_temp_libsvm_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_libsvm_file_fills_missing_indices_with_zero() {
+        let path = write_temp_libsvm_file(
+            "continuum_test_libsvm_basic.txt",
+            "1.0 1:3.0 3:5.0\n-1.0 2:7.0\n",
+        );
+
+        let (features, labels) = FeatureVector::from_libsvm_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(labels, vec![1.0, -1.0]);
+        assert_eq!(features[0].dimension(), 3);
+        assert_eq!(features[0].as_array().to_vec(), vec![3.0, 0.0, 5.0]);
+        assert_eq!(features[1].as_array().to_vec(), vec![0.0, 7.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_libsvm_file_skips_blank_lines() {
+        let path = write_temp_libsvm_file(
+            "continuum_test_libsvm_blank.txt",
+            "1.0 1:1.0\n\n0.0 1:2.0\n",
+        );
+
+        let (features, labels) = FeatureVector::from_libsvm_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(labels.len(), 2);
+        assert_eq!(features.len(), 2);
+    }
+
+    #[test]
+    fn test_from_libsvm_file_rejects_malformed_feature_field() {
+        let path = write_temp_libsvm_file(
+            "continuum_test_libsvm_malformed.txt",
+            "1.0 1:1.0 bogus\n",
+        );
+
+        let result = FeatureVector::from_libsvm_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(ModelError::SerializationError(_))));
+    }
+
+    #[test]
+    fn test_from_libsvm_file_rejects_invalid_label() {
+        // "1:1.0" is consumed as the label field here, which fails to parse as f32
+        let path = write_temp_libsvm_file("continuum_test_libsvm_bad_label.txt", "1:1.0\n");
+        let result = FeatureVector::from_libsvm_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(ModelError::SerializationError(_))));
+    }
 }
\ No newline at end of file
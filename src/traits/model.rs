@@ -1,5 +1,9 @@
 use std::error::Error;
 use std::fmt::{write, Display, Formatter, Result as FmtResult};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use crate::traits::features::FeatureVector;
 
 /// Custom error type for machine learning models
@@ -58,6 +62,89 @@ impl From<std::io::Error> for ModelError {
     }
 }
 
+/// Binary serialization format for `save_as`/`load_from`, alongside the JSON default
+/// used by `save`/`load`. Following burn's recorder redesign, the compact binary
+/// formats are opt-in cargo features so a minimal build can skip their dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationFormat {
+    /// Human-readable JSON; the `save`/`load` default
+    Json,
+    /// Compact binary encoding via `bincode`
+    #[cfg(feature = "bincode-format")]
+    Bincode,
+    /// Compact binary encoding via MessagePack (`rmp-serde`)
+    #[cfg(feature = "msgpack-format")]
+    MessagePack,
+    /// Infer the format from the file extension (`.bin` -> Bincode, `.msgpack` -> MessagePack,
+    /// anything else -> Json)
+    Auto,
+}
+
+/// Resolve `Auto` to a concrete format by sniffing `path`'s extension; any other
+/// format passes through unchanged
+fn resolve_format(path: &str, format: SerializationFormat) -> SerializationFormat {
+    if format != SerializationFormat::Auto {
+        return format;
+    }
+
+    let extension = std::path::Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or("");
+    match extension {
+        #[cfg(feature = "bincode-format")]
+        "bin" | "bincode" => SerializationFormat::Bincode,
+        #[cfg(feature = "msgpack-format")]
+        "msgpack" | "mp" => SerializationFormat::MessagePack,
+        _ => SerializationFormat::Json,
+    }
+}
+
+/// Serialize `model` to `path` in the given format, shared by every `Model` impl's
+/// `save_as` so the format-dispatch logic lives in one place
+pub(crate) fn write_model<T: Serialize>(model: &T, path: &str, format: SerializationFormat) -> Result<(), ModelError> {
+    let file = File::create(path)?;
+
+    match resolve_format(path, format) {
+        SerializationFormat::Json => {
+            let writer = BufWriter::new(file);
+            serde_json::to_writer(writer, model).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        #[cfg(feature = "bincode-format")]
+        SerializationFormat::Bincode => {
+            let writer = BufWriter::new(file);
+            bincode::serialize_into(writer, model).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        #[cfg(feature = "msgpack-format")]
+        SerializationFormat::MessagePack => {
+            let mut writer = BufWriter::new(file);
+            rmp_serde::encode::write(&mut writer, model).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        SerializationFormat::Auto => unreachable!("resolve_format never returns Auto"),
+    }
+}
+
+/// Deserialize a `T` from `path` in the given format, shared by every `Model` impl's
+/// `load_from` so the format-dispatch logic lives in one place
+pub(crate) fn read_model<T: DeserializeOwned>(path: &str, format: SerializationFormat) -> Result<T, ModelError> {
+    let file = File::open(path)?;
+
+    match resolve_format(path, format) {
+        SerializationFormat::Json => {
+            let reader = BufReader::new(file);
+            serde_json::from_reader(reader).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        #[cfg(feature = "bincode-format")]
+        SerializationFormat::Bincode => {
+            let reader = BufReader::new(file);
+            bincode::deserialize_from(reader).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        #[cfg(feature = "msgpack-format")]
+        SerializationFormat::MessagePack => {
+            let reader = BufReader::new(file);
+            rmp_serde::decode::from_read(reader).map_err(|e| ModelError::SerializationError(e.to_string()))
+        }
+        SerializationFormat::Auto => unreachable!("resolve_format never returns Auto"),
+    }
+}
+
 /// Trait for model validation metrics
 pub trait Metrics {
     /// Calculate mean squared error
@@ -90,6 +177,17 @@ pub trait Model: Send + Sync {
         Ok(predictions)
     }
     
+    /// Prime any lazily-initialized state (allocations, first-call caches) ahead of
+    /// serving traffic
+    ///
+    /// The default implementation is a no-op; models with expensive first-call setup
+    /// should override it. `AtomicModel::swap_models` calls this on the challenger
+    /// before writing it into `current`, so the cost is paid while the old model is
+    /// still serving, not on the first post-swap prediction.
+    fn warmup(&self) -> Result<(), ModelError> {
+        Ok(())
+    }
+
     /// Export model parameters
     fn export_parameters(&self) -> Result<Vec<f32>, ModelError>;
     
@@ -98,12 +196,50 @@ pub trait Model: Send + Sync {
     
     /// Validate the model using test data
     fn validate(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError>;
+
+    /// Train with optional per-sample weights (recency, confidence, or class balance)
+    ///
+    /// The default implementation ignores `sample_weights` and falls back to plain
+    /// `train`; models that support weighted fitting should override this.
+    fn train_weighted(
+        &mut self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<(), ModelError> {
+        let _ = sample_weights;
+        self.train(features, targets)
+    }
+
+    /// Validate with optional per-sample weights, producing a weighted MSE
+    ///
+    /// The default implementation ignores `sample_weights` and falls back to plain
+    /// `validate`; models that support weighted validation should override this.
+    fn validate_weighted(
+        &self,
+        features: &[FeatureVector],
+        targets: &[f32],
+        sample_weights: Option<&[f32]>,
+    ) -> Result<f32, ModelError> {
+        let _ = sample_weights;
+        self.validate(features, targets)
+    }
     
-    /// Save the model to a file
-    fn save(&self, path: &str) -> Result<(), ModelError>;
-    
-    /// Load the model from a file
-    fn load(&mut self, path: &str) -> Result<(), ModelError>;
+    /// Save the model to a file in the given format; `Auto` infers it from `path`'s extension
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
+    /// Load the model from a file in the given format; `Auto` infers it from `path`'s extension
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
+    /// Save the model to a file as JSON
+    fn save(&self, path: &str) -> Result<(), ModelError> {
+        self.save_as(path, SerializationFormat::Json)
+    }
+
+    /// Load the model from a JSON file
+    fn load(&mut self, path: &str) -> Result<(), ModelError> {
+        self.load_from(path, SerializationFormat::Json)
+    }
     
     /// Clone the model (needed for atomic swapping)
     fn clone_model(&self) -> Box<dyn Model>;
@@ -125,6 +261,13 @@ pub trait AsyncModel: Model {
     async fn validate_async(&self, features: &[FeatureVector], targets: &[f32]) -> Result<f32, ModelError>;
 }
 
+/// Trait for models that can report predictive uncertainty alongside a point estimate,
+/// borrowing the predict-variance idea from Gaussian-process surrogates
+pub trait UncertaintyModel: Model {
+    /// Predict both the mean and the variance of the prediction for `feature`
+    fn predict_with_variance(&self, feature: &FeatureVector) -> Result<(f32, f32), ModelError>;
+}
+
 /// Trait for models that can be updated incrementally (online learning)
 pub trait IncrementalModel: Model {
     /// Update the model with new training examples without full retraining
@@ -137,6 +280,40 @@ pub trait IncrementalModel: Model {
     fn get_parameters(&self) -> Vec<f32>;
 }
 
+/// Trait for models that assign an input to one of a fixed set of classes, each scored by a
+/// per-class strength, rather than the single scalar `Model::predict` produces
+///
+/// Kept separate from `Model` since classification's output shape (one score per class) and
+/// training labels (discrete class indices rather than continuous targets) don't fit `Model`'s
+/// regression-oriented interface; `ModelServer` dispatches to this trait for `classify`/
+/// `classify_batch` rather than `predict`/`predict_batch`.
+pub trait Classifier: Send + Sync {
+    /// Train on `features` labeled by `labels`, indices into `classes()`
+    fn train(&mut self, features: &[FeatureVector], labels: &[usize]) -> Result<(), ModelError>;
+
+    /// Score every class for a single input; higher is a stronger match for that class.
+    /// Not guaranteed to be a normalized probability distribution -- see each
+    /// implementation's doc comment.
+    fn predict_scores(&self, feature: &FeatureVector) -> Result<Vec<f32>, ModelError>;
+
+    /// Score every class for multiple inputs
+    fn predict_scores_batch(&self, features: &[FeatureVector]) -> Result<Vec<Vec<f32>>, ModelError> {
+        features.iter().map(|feature| self.predict_scores(feature)).collect()
+    }
+
+    /// Class names, in the same order as `predict_scores`'s output
+    fn classes(&self) -> &[String];
+
+    /// Save the classifier to a file in the given format; `Auto` infers it from `path`'s extension
+    fn save_as(&self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
+    /// Load the classifier from a file in the given format; `Auto` infers it from `path`'s extension
+    fn load_from(&mut self, path: &str, format: SerializationFormat) -> Result<(), ModelError>;
+
+    /// Clone the classifier (needed for `ModelServer`'s boxed registry)
+    fn clone_classifier(&self) -> Box<dyn Classifier>;
+}
+
 /// Factory trait for creating new model instances
 pub trait ModelFactory: Send + Sync {
     /// Create a new instance of the model with default parameters